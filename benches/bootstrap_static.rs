@@ -0,0 +1,52 @@
+//! Benchmarks of the methods looking data up in the cached static data, which should only take
+//! the time of a lookup in its indices, rather than of a scan or a copy of the whole static data.
+//!
+//! Run with `cargo bench`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use fpl_rs::{
+    models::bootstrap_static::{BootstrapStatic, Player},
+    Fpl,
+};
+use tokio::runtime::Runtime;
+use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+/// Returns an instance whose cached static data holds `players` players.
+async fn warmed_fpl(mock_server: &MockServer, players: i64) -> Fpl {
+    let bootstrap_static = BootstrapStatic {
+        elements: (1..=players)
+            .map(|id| Player {
+                id,
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    };
+    Mock::given(path("/bootstrap-static/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(bootstrap_static))
+        .mount(mock_server)
+        .await;
+    let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+    fpl.get_bootstrap_static().await.unwrap();
+    fpl
+}
+
+fn bench_get_player(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("get_player");
+    for players in [700, 7_000] {
+        let (_mock_server, fpl) = runtime.block_on(async {
+            let mock_server = MockServer::start().await;
+            let fpl = warmed_fpl(&mock_server, players).await;
+            (mock_server, fpl)
+        });
+        // The last player is the slowest to find by scanning.
+        group.bench_with_input(BenchmarkId::from_parameter(players), &players, |b, &id| {
+            b.to_async(&runtime).iter(|| fpl.get_player(id));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_get_player);
+criterion_main!(benches);
@@ -0,0 +1,207 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+use futures::lock::Mutex;
+use reqwest::StatusCode;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use crate::conditional::{Conditional, Validators};
+use crate::fpl_error::FplError;
+
+/// A value fetched from the FPL API and kept, along with when it was fetched, shared by the clones
+/// of `Fpl` so that methods reading it only need `&self`.
+///
+/// The value is only locked while it is read or stored, never while it is fetched. Fetches are
+/// made one at a time instead, so that concurrent calls finding no fresh value fetch it once and
+/// reuse it rather than all fetching it, even when the value fetched is not considered fresh, such
+/// as fixtures which are not finished yet. Once a value is cached, it is fetched again with the
+/// validators of the response it came with, so that it is only sent again if it has changed.
+pub(crate) struct Cache<T> {
+    /// What is cached, to tell caches apart in traces.
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    name: &'static str,
+    /// The cached value, if any.
+    entry: RwLock<Option<Entry<T>>>,
+    /// Held while the value is fetched.
+    fetching: Mutex<()>,
+    /// How many fetches have succeeded, to tell whether one did while a call was waiting for `fetching`.
+    generation: AtomicU64,
+}
+
+/// A cached value, with when it was fetched or last found unchanged, and the validators to fetch it again with.
+struct Entry<T> {
+    value: T,
+    fetched_at: Instant,
+    validators: Validators,
+}
+
+impl<T: Clone> Cache<T> {
+    /// Creates an empty cache.
+    pub(crate) fn new(name: &'static str) -> Cache<T> {
+        Cache {
+            name,
+            entry: RwLock::new(None),
+            fetching: Mutex::new(()),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns what `read` returns for the cached value and when it was fetched,
+    /// or `None` if no value is cached.
+    pub(crate) fn read<R>(&self, read: impl FnOnce(&T, Instant) -> R) -> Option<R> {
+        self.entry_read()
+            .as_ref()
+            .map(|entry| read(&entry.value, entry.fetched_at))
+    }
+
+    /// Replaces the cached value with `value`, fetched now without validators.
+    #[cfg(test)]
+    pub(crate) fn set(&self, value: T) {
+        self.store(value, Validators::default());
+    }
+
+    /// Replaces the cached value with `value`, fetched at `fetched_at` without validators,
+    /// such as when it is loaded from a file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn restore(&self, value: T, fetched_at: Instant) {
+        *self.entry_write() = Some(Entry {
+            value,
+            fetched_at,
+            validators: Validators::default(),
+        });
+    }
+
+    /// Discards the cached value.
+    pub(crate) fn clear(&self) {
+        *self.entry_write() = None;
+    }
+
+    /// Asynchronously returns the cached value if `is_fresh` holds for it,
+    /// or fetches it with `fetch`, given the validators of the cached value, and caches it otherwise.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the error of `fetch` if the value is fetched and fetching it fails.
+    pub(crate) async fn get_or_fetch<F, Fut>(
+        &self,
+        is_fresh: impl Fn(&T, Instant) -> bool,
+        fetch: F,
+    ) -> Result<T, FplError>
+    where
+        F: FnOnce(Option<Validators>) -> Fut,
+        Fut: Future<Output = Result<Conditional<T>, FplError>>,
+    {
+        let generation = self.generation.load(Ordering::Acquire);
+        if let Some(value) = self.fresh(&is_fresh) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("{} cache hit", self.name);
+            return Ok(value);
+        }
+        let _fetching = self.fetching.lock().await;
+        // Another call may have fetched the value while this one was waiting.
+        if let Some(value) = self
+            .fresh(&is_fresh)
+            .or_else(|| self.fetched_since(generation))
+        {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("{} cache hit", self.name);
+            return Ok(value);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!("{} cache miss", self.name);
+        self.fetch_unlocked(fetch).await
+    }
+
+    /// Asynchronously fetches the value with `fetch`, given the validators of the cached value,
+    /// and caches it, whether a value is cached or not.
+    ///
+    /// If another call fetches the value while this one waits for it to be done, its value is
+    /// returned instead of fetching the value again.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the error of `fetch` if fetching the value fails.
+    pub(crate) async fn fetch<F, Fut>(&self, fetch: F) -> Result<T, FplError>
+    where
+        F: FnOnce(Option<Validators>) -> Fut,
+        Fut: Future<Output = Result<Conditional<T>, FplError>>,
+    {
+        let generation = self.generation.load(Ordering::Acquire);
+        let _fetching = self.fetching.lock().await;
+        if let Some(value) = self.fetched_since(generation) {
+            return Ok(value);
+        }
+        self.fetch_unlocked(fetch).await
+    }
+
+    /// Asynchronously fetches the value with `fetch`, given the validators of the cached value, and
+    /// caches it, or keeps the cached value if it has not changed.
+    ///
+    /// The caller must hold `fetching`.
+    async fn fetch_unlocked<F, Fut>(&self, fetch: F) -> Result<T, FplError>
+    where
+        F: FnOnce(Option<Validators>) -> Fut,
+        Fut: Future<Output = Result<Conditional<T>, FplError>>,
+    {
+        let validators = self
+            .entry_read()
+            .as_ref()
+            .map(|entry| entry.validators.clone())
+            .filter(|validators| !validators.is_empty());
+        match fetch(validators).await? {
+            Conditional::Modified(value, validators) => {
+                self.store(value.clone(), validators);
+                self.generation.fetch_add(1, Ordering::Release);
+                Ok(value)
+            }
+            Conditional::NotModified => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("{} cache revalidated", self.name);
+                let mut entry = self.entry_write();
+                // The cached value may have been discarded while it was fetched.
+                let entry = entry
+                    .as_mut()
+                    .ok_or(FplError::HttpStatus(StatusCode::NOT_MODIFIED))?;
+                entry.fetched_at = Instant::now();
+                self.generation.fetch_add(1, Ordering::Release);
+                Ok(entry.value.clone())
+            }
+        }
+    }
+
+    /// Replaces the cached value with `value`, fetched now with `validators`.
+    fn store(&self, value: T, validators: Validators) {
+        *self.entry_write() = Some(Entry {
+            value,
+            fetched_at: Instant::now(),
+            validators,
+        });
+    }
+
+    /// Returns a copy of the cached value if a fetch has succeeded since `generation` was loaded.
+    fn fetched_since(&self, generation: u64) -> Option<T> {
+        if self.generation.load(Ordering::Acquire) == generation {
+            return None;
+        }
+        self.read(|value, _| value.clone())
+    }
+
+    /// Returns a copy of the cached value if `is_fresh` holds for it.
+    fn fresh(&self, is_fresh: impl Fn(&T, Instant) -> bool) -> Option<T> {
+        self.read(|value, fetched_at| is_fresh(value, fetched_at).then(|| value.clone()))
+            .flatten()
+    }
+
+    // A panic while the entry is locked can't leave it inconsistent, since it is only replaced whole.
+    fn entry_read(&self) -> RwLockReadGuard<'_, Option<Entry<T>>> {
+        self.entry.read().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn entry_write(&self) -> RwLockWriteGuard<'_, Option<Entry<T>>> {
+        self.entry.write().unwrap_or_else(|err| err.into_inner())
+    }
+}
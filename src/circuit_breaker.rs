@@ -0,0 +1,159 @@
+use std::sync::Mutex;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use crate::fpl_error::FplError;
+
+/// The state of the circuit breaker of the `Fpl` API wrapper, read with
+/// [`Fpl::circuit_state`](../struct.Fpl.html#method.circuit_state).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are made, counting how many fail in a row.
+    Closed,
+    /// Too many requests have failed in a row, so requests fail with `FplError::CircuitOpen`
+    /// without being made until the cooldown has elapsed and the next one probes the FPL API.
+    Open,
+    /// The cooldown has elapsed and one request is being made to probe the FPL API, which closes
+    /// the circuit if it succeeds or opens it again otherwise.
+    HalfOpen,
+}
+
+/// Circuit breaker failing requests at once after too many have failed in a row, shared by the
+/// clones of `Fpl`, so that an unavailable FPL API is not sent requests bound to fail.
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    /// How many requests failing in a row open the circuit.
+    failure_threshold: u32,
+    /// How long the circuit stays open before a request probes the FPL API.
+    cooldown: Duration,
+    /// The state of the circuit, with when it was opened or the probe was let through.
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BreakerState {
+    Closed { failures: u32 },
+    Open { since: Instant },
+    HalfOpen { since: Instant },
+}
+
+impl CircuitBreaker {
+    /// Creates a closed circuit breaker opening after `failure_threshold` failures in a row,
+    /// for `cooldown`.
+    pub(crate) fn new(failure_threshold: u32, cooldown: Duration) -> CircuitBreaker {
+        CircuitBreaker {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            state: Mutex::new(BreakerState::Closed { failures: 0 }),
+        }
+    }
+
+    /// Returns the state of the circuit.
+    pub(crate) fn state(&self) -> CircuitState {
+        match *self.lock() {
+            BreakerState::Closed { .. } => CircuitState::Closed,
+            BreakerState::Open { .. } => CircuitState::Open,
+            BreakerState::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Checks whether a request can be made, letting one request through to probe the FPL API
+    /// once the cooldown has elapsed.
+    ///
+    /// A probe which never reports its outcome, such as when it is cancelled, is replaced by
+    /// another one after the cooldown.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `FplError::CircuitOpen` if the circuit is open, or a probe is being made.
+    pub(crate) fn acquire(&self) -> Result<(), FplError> {
+        let mut state = self.lock();
+        match *state {
+            BreakerState::Closed { .. } => Ok(()),
+            BreakerState::Open { since } | BreakerState::HalfOpen { since }
+                if since.elapsed() >= self.cooldown =>
+            {
+                *state = BreakerState::HalfOpen {
+                    since: Instant::now(),
+                };
+                Ok(())
+            }
+            _ => Err(FplError::CircuitOpen),
+        }
+    }
+
+    /// Records the outcome of a request, opening the circuit after too many failures in a row and
+    /// closing it after any success.
+    ///
+    /// Only failures showing that the FPL API is unavailable are counted, that is failures
+    /// without a response, server errors, blocked requests, too many requests and game updates.
+    pub(crate) fn record<T>(&self, result: &Result<T, FplError>) {
+        let failed = result.as_ref().err().is_some_and(|err| {
+            matches!(
+                err,
+                FplError::Request(_)
+                    | FplError::Timeout(_)
+                    | FplError::Blocked(_)
+                    | FplError::RateLimited { .. }
+                    | FplError::GameUpdating
+            ) || err.status().is_some_and(|status| status.is_server_error())
+        });
+        let mut state = self.lock();
+        let next_state = match (*state, failed) {
+            (_, false) => BreakerState::Closed { failures: 0 },
+            (BreakerState::Closed { failures }, true) if failures + 1 < self.failure_threshold => {
+                BreakerState::Closed {
+                    failures: failures + 1,
+                }
+            }
+            // Requests let through before the circuit was opened don't extend the cooldown.
+            (BreakerState::Open { since }, true) => BreakerState::Open { since },
+            (_, true) => BreakerState::Open {
+                since: Instant::now(),
+            },
+        };
+        #[cfg(feature = "tracing")]
+        if matches!(next_state, BreakerState::Open { .. })
+            && !matches!(*state, BreakerState::Open { .. })
+        {
+            tracing::warn!(cooldown = ?self.cooldown, "FPL API circuit breaker opened");
+        }
+        *state = next_state;
+    }
+
+    /// Locks the state of the circuit, which a panic can't leave inconsistent.
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe() {
+        let circuit_breaker = CircuitBreaker::new(2, Duration::ZERO);
+        let failure: Result<(), FplError> = Err(FplError::GameUpdating);
+        circuit_breaker.record(&failure);
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+        circuit_breaker.record(&Err::<(), _>(FplError::NotFound));
+        circuit_breaker.record(&failure);
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+        circuit_breaker.record(&failure);
+        assert_eq!(circuit_breaker.state(), CircuitState::Open);
+
+        assert!(circuit_breaker.acquire().is_ok());
+        assert_eq!(circuit_breaker.state(), CircuitState::HalfOpen);
+        circuit_breaker.record(&failure);
+        assert_eq!(circuit_breaker.state(), CircuitState::Open);
+
+        assert!(circuit_breaker.acquire().is_ok());
+        circuit_breaker.record(&Ok(()));
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+    }
+}
@@ -0,0 +1,56 @@
+use reqwest::header::{HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{RequestBuilder, Response};
+
+/// The `ETag` and `Last-Modified` headers of a response, which make a later request for the same
+/// URL conditional, so that the FPL API answers it with `304 Not Modified` and no body if the
+/// resource has not changed.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Validators {
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+}
+
+impl Validators {
+    /// Returns the validators of `response`, which has none if the FPL API sent none.
+    pub(crate) fn from_response(response: &Response) -> Validators {
+        Validators {
+            etag: response.headers().get(ETAG).cloned(),
+            last_modified: response.headers().get(LAST_MODIFIED).cloned(),
+        }
+    }
+
+    /// Makes `request` conditional on the resource having changed since these validators were sent.
+    pub(crate) fn apply(&self, mut request: RequestBuilder) -> RequestBuilder {
+        if let Some(etag) = &self.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &self.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+        request
+    }
+
+    /// Returns whether there are no validators to make a request conditional with.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+/// The outcome of a request which may be conditional.
+#[derive(Debug)]
+pub(crate) enum Conditional<T> {
+    /// The resource was sent, with the validators to make the next request for it conditional.
+    Modified(T, Validators),
+    /// The resource has not changed since the validators the request was made with were sent.
+    NotModified,
+}
+
+impl<T> Conditional<T> {
+    /// Maps the resource sent, if it was.
+    pub(crate) fn map<U>(self, f: impl FnOnce(T) -> U) -> Conditional<U> {
+        match self {
+            Conditional::Modified(value, validators) => Conditional::Modified(f(value), validators),
+            Conditional::NotModified => Conditional::NotModified,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::fpl_error::FplError;
+
+/// The file the static data is saved to in the cache directory.
+pub(crate) const BOOTSTRAP_STATIC_FILE: &str = "bootstrap-static.json";
+
+/// The file the fixtures are saved to in the cache directory.
+pub(crate) const FIXTURES_FILE: &str = "fixtures.json";
+
+/// A directory the data fetched from the FPL API is saved to, so that the next instances of `Fpl`,
+/// such as in the next runs of a command line tool, can load it instead of fetching it again.
+#[derive(Debug, Clone)]
+pub(crate) struct DiskCache {
+    dir: PathBuf,
+}
+
+/// The content of a cache file: the data, with when it was fetched.
+#[derive(Serialize, Deserialize)]
+struct Snapshot<T> {
+    /// When the data was fetched, in seconds since the Unix epoch.
+    fetched_at: u64,
+    data: T,
+}
+
+impl DiskCache {
+    /// Creates a cache saving its files in `dir`, which is created when the first file is saved.
+    pub(crate) fn new(dir: PathBuf) -> DiskCache {
+        DiskCache { dir }
+    }
+
+    /// Loads the data saved in the file `name`, with when it was fetched, if it was fetched less than
+    /// `ttl` ago.
+    ///
+    /// A missing, unreadable, corrupted or outdated file is ignored, so that the data is fetched again.
+    pub(crate) fn load<T: DeserializeOwned>(
+        &self,
+        name: &str,
+        ttl: Duration,
+    ) -> Option<(T, Instant)> {
+        let json = std::fs::read_to_string(self.dir.join(name)).ok()?;
+        let snapshot: Snapshot<T> = serde_json::from_str(&json).ok()?;
+        let age = now().checked_sub(Duration::from_secs(snapshot.fetched_at))?;
+        if age >= ttl {
+            return None;
+        }
+        Some((snapshot.data, Instant::now().checked_sub(age)?))
+    }
+
+    /// Asynchronously saves `data`, fetched now, to the file `name`.
+    ///
+    /// The data is written to a temporary file first, which then replaces the file, so that a file is
+    /// never left half written. Failures are ignored, since the data is fetched again when it cannot be loaded.
+    pub(crate) async fn save<T: Serialize>(&self, name: &str, data: &T) {
+        let snapshot = Snapshot {
+            fetched_at: now().as_secs(),
+            data,
+        };
+        let Ok(json) = serde_json::to_string(&snapshot) else {
+            return;
+        };
+        let path = self.dir.join(name);
+        let temporary_path = self.dir.join(format!("{}.tmp", name));
+        let result = async {
+            tokio::fs::create_dir_all(&self.dir).await?;
+            tokio::fs::write(&temporary_path, json).await?;
+            tokio::fs::rename(&temporary_path, &path).await
+        }
+        .await;
+        #[cfg(feature = "tracing")]
+        if let Err(err) = result {
+            tracing::warn!(path = %path.display(), %err, "failed to save the FPL API cache file");
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = result;
+    }
+
+    /// Removes the saved files.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `FplError::Other` if a file exists but cannot be removed.
+    pub(crate) fn clear(&self) -> Result<(), FplError> {
+        for name in [BOOTSTRAP_STATIC_FILE, FIXTURES_FILE] {
+            let path = self.dir.join(name);
+            match std::fs::remove_file(&path) {
+                Err(err) if err.kind() != ErrorKind::NotFound => {
+                    return Err(FplError::Other(format!(
+                        "Failed when removing the cache file: {} with this error: {}",
+                        path.display(),
+                        err
+                    )));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the time elapsed since the Unix epoch.
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
@@ -0,0 +1,500 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(target_arch = "wasm32")]
+use reqwest::header::{HeaderValue, USER_AGENT};
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Proxy;
+use reqwest::{header::HeaderMap, Client, ClientBuilder};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::disk_cache::{DiskCache, BOOTSTRAP_STATIC_FILE, FIXTURES_FILE};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::rate_limiter::RateLimiter;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::retry_policy::RetryPolicy;
+use crate::{
+    cache::Cache,
+    circuit_breaker::CircuitBreaker,
+    fpl_error::FplError,
+    metrics::Metrics,
+    request_observer::{RequestObserver, SharedObserver},
+    single_flight::SingleFlight,
+    Fpl,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+    models::{bootstrap_static::BootstrapStatic, fixture::Fixtures},
+    static_data::StaticData,
+};
+
+/// The URL of the FPL API, which requests are made to when no other base URL is configured.
+pub const DEFAULT_BASE_URL: &str = "https://fantasy.premierleague.com/api/";
+
+/// The URL of the FPL login page, which credentials are posted to when no other login URL is configured.
+pub const DEFAULT_LOGIN_URL: &str = "https://users.premierleague.com/accounts/login/";
+
+/// How long the static data is cached when no other time to live is configured.
+pub const DEFAULT_BOOTSTRAP_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// The timeout applied to requests when none is configured.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The `User-Agent` header sent with every request when no other user agent is configured.
+pub const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// The time waited before the first retry of a failed request when no other delay is configured.
+pub const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// How many requests a batch method makes at once when no other limit is configured.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// What a method making a batch of requests does when one of them fails.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchFailureMode {
+    /// Stop at the first failed request and return its error.
+    #[default]
+    FailFast,
+    /// Return the result of every request, whether it failed or not.
+    Partial,
+}
+
+/// Builder for the `Fpl` API wrapper.
+///
+/// The `FplBuilder` struct allows configuring how the `Fpl` API wrapper makes its requests
+/// before creating it.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use fpl_rs::{fpl_error::FplError, Fpl};
+///
+/// fn main() -> Result<(), FplError> {
+///     // Create a new Fpl instance which gives up on requests after 10 seconds
+///     let fpl = Fpl::builder().timeout(Duration::from_secs(10)).build()?;
+///
+///     // Use the Fpl instance to make API requests
+///     // ...
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FplBuilder {
+    /// The total time allowed for a request, from connecting until the response body is read.
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Duration,
+    /// The time allowed for connecting to the server, if any.
+    #[cfg(not(target_arch = "wasm32"))]
+    connect_timeout: Option<Duration>,
+    /// The `User-Agent` header sent with every request.
+    user_agent: String,
+    /// The proxies requests are made through.
+    #[cfg(not(target_arch = "wasm32"))]
+    proxies: Vec<Proxy>,
+    /// Whether the proxies of the `HTTP_PROXY` and `HTTPS_PROXY` environment variables are ignored.
+    #[cfg(not(target_arch = "wasm32"))]
+    no_proxy: bool,
+    /// Whether responses are requested compressed with gzip or brotli, and decompressed.
+    #[cfg(not(target_arch = "wasm32"))]
+    compression: bool,
+    /// The headers sent with every request.
+    default_headers: HeaderMap,
+    /// The HTTP client to use instead of building one, if any.
+    client: Option<Client>,
+    /// The URL every FPL API request is made relative to.
+    base_url: String,
+    /// The URL of the login page the credentials are posted to.
+    login_url: String,
+    /// How long the static data is used before being fetched again.
+    bootstrap_ttl: Duration,
+    /// The directory the static data and fixtures are saved to, if any.
+    #[cfg(not(target_arch = "wasm32"))]
+    cache_dir: Option<PathBuf>,
+    /// How many requests a batch method makes at once.
+    concurrency: usize,
+    /// What a batch method does when one of its requests fails.
+    batch_failure_mode: BatchFailureMode,
+    /// The observer of every request made, if any.
+    observer: Option<SharedObserver>,
+    /// Whether the requests made are counted.
+    collect_metrics: bool,
+    /// How many requests failing in a row open the circuit breaker, and for how long, if enabled.
+    circuit_breaker: Option<(u32, Duration)>,
+    /// How many requests can be made over a period, if limited.
+    #[cfg(not(target_arch = "wasm32"))]
+    rate_limit: Option<(u32, Duration)>,
+    /// How requests failing transiently are retried.
+    #[cfg(not(target_arch = "wasm32"))]
+    retry_policy: RetryPolicy,
+    /// How long a request waits for the game to be updated, if it does.
+    #[cfg(not(target_arch = "wasm32"))]
+    game_update_wait: Option<Duration>,
+}
+
+impl Default for FplBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FplBuilder {
+    /// Creates a new builder with the default configuration.
+    pub fn new() -> FplBuilder {
+        FplBuilder {
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: DEFAULT_TIMEOUT,
+            #[cfg(not(target_arch = "wasm32"))]
+            connect_timeout: None,
+            user_agent: String::from(DEFAULT_USER_AGENT),
+            #[cfg(not(target_arch = "wasm32"))]
+            proxies: Vec::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            no_proxy: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            compression: true,
+            default_headers: HeaderMap::new(),
+            client: None,
+            base_url: String::from(DEFAULT_BASE_URL),
+            login_url: String::from(DEFAULT_LOGIN_URL),
+            bootstrap_ttl: DEFAULT_BOOTSTRAP_TTL,
+            #[cfg(not(target_arch = "wasm32"))]
+            cache_dir: None,
+            concurrency: DEFAULT_CONCURRENCY,
+            batch_failure_mode: BatchFailureMode::default(),
+            observer: None,
+            collect_metrics: false,
+            circuit_breaker: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            rate_limit: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            retry_policy: RetryPolicy {
+                max_retries: 0,
+                base_delay: DEFAULT_RETRY_BASE_DELAY,
+            },
+            #[cfg(not(target_arch = "wasm32"))]
+            game_update_wait: None,
+        }
+    }
+
+    /// Sets the total time allowed for a request, from connecting until the response body is read.
+    ///
+    /// Requests taking longer fail with an `FplError`. Defaults to 30 seconds.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(mut self, timeout: Duration) -> FplBuilder {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the time allowed for connecting to the server.
+    ///
+    /// This is included in the overall timeout. Defaults to no connect timeout.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> FplBuilder {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    ///
+    /// Defaults to the name and version of this crate, such as `fpl_rs/0.1.2`.
+    pub fn user_agent(mut self, user_agent: &str) -> FplBuilder {
+        self.user_agent = String::from(user_agent);
+        self
+    }
+
+    /// Adds a proxy requests are made through.
+    ///
+    /// Can be called several times, such as to use different proxies for HTTP and HTTPS.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: Proxy) -> FplBuilder {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Ignores the proxies of the `HTTP_PROXY` and `HTTPS_PROXY` environment variables,
+    /// which requests are otherwise made through.
+    ///
+    /// Proxies added with [`proxy`](struct.FplBuilder.html#method.proxy) are still used.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn no_proxy(mut self) -> FplBuilder {
+        self.no_proxy = true;
+        self
+    }
+
+    /// Sets whether responses are requested compressed with gzip or brotli, by sending an
+    /// `Accept-Encoding` header, and decompressed when they are.
+    ///
+    /// The static data and fixtures compress to less than a tenth of their size, so this saves a lot of
+    /// bandwidth when polling the FPL API. Defaults to `true`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn compression(mut self, compression: bool) -> FplBuilder {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets the headers sent with every request.
+    pub fn default_headers(mut self, default_headers: HeaderMap) -> FplBuilder {
+        self.default_headers = default_headers;
+        self
+    }
+
+    /// Sets the HTTP client requests are made with, instead of building one.
+    ///
+    /// This is useful to share a connection pool or a proxy configuration with the rest of an
+    /// application. The timeouts, user agent, proxies, compression and default headers of this builder are
+    /// then ignored in favour of those of the client. The client needs its cookie store enabled
+    /// for [`login`](../struct.Fpl.html#method.login) to keep the session.
+    pub fn client(mut self, client: Client) -> FplBuilder {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the URL every FPL API request is made relative to.
+    ///
+    /// This is useful to make requests to a mock server when testing. A trailing `/` is added
+    /// when missing. Defaults to `https://fantasy.premierleague.com/api/`.
+    pub fn base_url(mut self, base_url: &str) -> FplBuilder {
+        self.base_url = match base_url.ends_with('/') {
+            true => String::from(base_url),
+            false => format!("{}/", base_url),
+        };
+        self
+    }
+
+    /// Sets the URL of the login page the credentials are posted to.
+    ///
+    /// This is useful to log in to a mock server when testing.
+    /// Defaults to `https://users.premierleague.com/accounts/login/`.
+    pub fn login_url(mut self, login_url: &str) -> FplBuilder {
+        self.login_url = String::from(login_url);
+        self
+    }
+
+    /// Sets how long the static data is cached before being fetched again.
+    ///
+    /// The static data holds player prices and injury news, which change over time.
+    /// Defaults to one hour.
+    pub fn bootstrap_ttl(mut self, bootstrap_ttl: Duration) -> FplBuilder {
+        self.bootstrap_ttl = bootstrap_ttl;
+        self
+    }
+
+    /// Saves the static data and fixtures to JSON files in `cache_dir` whenever they are fetched,
+    /// and loads them when the `Fpl` instance is created, if they were fetched less than the
+    /// [`bootstrap_ttl`](struct.FplBuilder.html#method.bootstrap_ttl) ago, instead of fetching them again.
+    ///
+    /// This is useful for command line tools, which would otherwise fetch the static data on every run.
+    /// Files which cannot be read, such as corrupted ones or ones saved by another version of this crate,
+    /// are ignored and the data is fetched again. Remove the files with
+    /// [`Fpl::invalidate_disk_cache`](../struct.Fpl.html#method.invalidate_disk_cache).
+    /// Defaults to not saving the data.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> FplBuilder {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    /// Sets how many requests a batch method, such as `get_player_summaries`, makes at once.
+    ///
+    /// A limit of 0 is treated as 1. Defaults to 8.
+    pub fn concurrency(mut self, concurrency: usize) -> FplBuilder {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets what a batch method, such as `get_player_summaries`, does when one of its requests fails.
+    ///
+    /// Defaults to `BatchFailureMode::FailFast`.
+    pub fn batch_failure_mode(mut self, batch_failure_mode: BatchFailureMode) -> FplBuilder {
+        self.batch_failure_mode = batch_failure_mode;
+        self
+    }
+
+    /// Sets the observer of every request made, such as for logging or metrics.
+    ///
+    /// Defaults to no observer.
+    pub fn observer<O>(mut self, observer: O) -> FplBuilder
+    where
+        O: RequestObserver + 'static,
+    {
+        self.observer = Some(SharedObserver(Arc::new(observer)));
+        self
+    }
+
+    /// Counts the requests made, their failures, the bytes received and their latency,
+    /// which [`Fpl::metrics`](../struct.Fpl.html#method.metrics) reports.
+    ///
+    /// Defaults to not counting the requests.
+    pub fn collect_metrics(mut self) -> FplBuilder {
+        self.collect_metrics = true;
+        self
+    }
+
+    /// Makes requests fail at once with `FplError::CircuitOpen` for `cooldown` after
+    /// `failure_threshold` requests have failed in a row, such as when the FPL API is down.
+    ///
+    /// Only failures showing that the FPL API is unavailable are counted: failures without a
+    /// response, server errors, blocked requests, too many requests and game updates. Once the
+    /// cooldown has elapsed, one request is made to probe the FPL API, which closes the circuit if it
+    /// succeeds or opens it again otherwise. Any success resets the count. The circuit is shared with
+    /// the clones of the `Fpl` instance, and its state is reported by
+    /// [`Fpl::circuit_state`](../struct.Fpl.html#method.circuit_state). Defaults to no circuit breaker.
+    pub fn circuit_breaker(mut self, failure_threshold: u32, cooldown: Duration) -> FplBuilder {
+        self.circuit_breaker = Some((failure_threshold, cooldown));
+        self
+    }
+
+    /// Limits the requests made to `max_requests` every `per` period, such as to avoid being blocked
+    /// when fetching the picks of every member of a large league.
+    ///
+    /// Requests over the limit wait for their turn instead of failing. Up to `max_requests` requests
+    /// can be made at once after a quiet period. The limit is shared with the clones of the `Fpl`
+    /// instance. Defaults to no limit.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn rate_limit(mut self, max_requests: u32, per: Duration) -> FplBuilder {
+        self.rate_limit = Some((max_requests, per));
+        self
+    }
+
+    /// Limits the requests made to `requests_per_second` every second.
+    ///
+    /// This is a shorthand for [`rate_limit`](struct.FplBuilder.html#method.rate_limit) over one second.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn requests_per_second(self, requests_per_second: u32) -> FplBuilder {
+        self.rate_limit(requests_per_second, Duration::from_secs(1))
+    }
+
+    /// Sets how many times a request is retried when it fails transiently, such as when the FPL API
+    /// is under heavy load around deadlines.
+    ///
+    /// Only requests fetching data are retried, and only after a network failure, a server error
+    /// or too many requests, waiting longer before every retry. Other failures, such as a resource
+    /// that is not found, are returned at once. Defaults to no retries.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn max_retries(mut self, max_retries: u32) -> FplBuilder {
+        self.retry_policy.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the time waited before the first retry of a request, doubled for every following one
+    /// and shortened by a random amount of up to half of it.
+    ///
+    /// Defaults to 500 milliseconds.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn base_delay(mut self, base_delay: Duration) -> FplBuilder {
+        self.retry_policy.base_delay = base_delay;
+        self
+    }
+
+    /// Makes requests fetching data wait for up to `wait` while the game is being updated,
+    /// which happens for a while after every deadline, instead of failing with
+    /// `FplError::GameUpdating`.
+    ///
+    /// The request is made again every tenth of `wait`, and at least every 30 seconds, until the
+    /// game is updated or `wait` has elapsed. Defaults to not waiting.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn wait_for_game_update(mut self, wait: Duration) -> FplBuilder {
+        self.game_update_wait = Some(wait);
+        self
+    }
+
+    /// Creates the `Fpl` API wrapper with this configuration.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `FplError::Client` if the HTTP client cannot be built,
+    /// such as when the TLS backend cannot be initialized.
+    pub fn build(self) -> Result<Fpl, FplError> {
+        let http_client = match &self.client {
+            Some(client) => client.clone(),
+            None => self.build_client()?,
+        };
+        let bootstrap_static = Arc::new(Cache::new("bootstrap-static"));
+        let fixtures = Arc::new(Cache::new("fixtures"));
+        #[cfg(not(target_arch = "wasm32"))]
+        let disk_cache = self.cache_dir.map(DiskCache::new);
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(disk_cache) = &disk_cache {
+            if let Some((data, fetched_at)) =
+                disk_cache.load::<BootstrapStatic>(BOOTSTRAP_STATIC_FILE, self.bootstrap_ttl)
+            {
+                bootstrap_static.restore(Arc::new(StaticData::new(data)), fetched_at);
+            }
+            if let Some((data, fetched_at)) =
+                disk_cache.load::<Fixtures>(FIXTURES_FILE, self.bootstrap_ttl)
+            {
+                fixtures.restore(data, fetched_at);
+            }
+        }
+        Ok(Fpl {
+            bootstrap_static,
+            fixtures,
+            live_gameweeks: Arc::new(SingleFlight::new()),
+            #[cfg(not(target_arch = "wasm32"))]
+            disk_cache,
+            bootstrap_ttl: self.bootstrap_ttl,
+            http_client,
+            authenticated: false,
+            base_url: self.base_url,
+            login_url: self.login_url,
+            concurrency: self.concurrency,
+            batch_failure_mode: self.batch_failure_mode,
+            observer: self.observer,
+            metrics: self.collect_metrics.then(|| Arc::new(Metrics::default())),
+            circuit_breaker: self.circuit_breaker.map(|(failure_threshold, cooldown)| {
+                Arc::new(CircuitBreaker::new(failure_threshold, cooldown))
+            }),
+            #[cfg(not(target_arch = "wasm32"))]
+            rate_limiter: self
+                .rate_limit
+                .map(|(max_requests, per)| Arc::new(RateLimiter::new(max_requests, per))),
+            #[cfg(not(target_arch = "wasm32"))]
+            retry_policy: self.retry_policy,
+            #[cfg(not(target_arch = "wasm32"))]
+            game_update_wait: self.game_update_wait,
+        })
+    }
+
+    /// Builds the HTTP client with the timeouts, user agent, proxies, compression and default headers of this builder.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn build_client(&self) -> Result<Client, FplError> {
+        let mut client_builder = ClientBuilder::new()
+            .default_headers(self.default_headers.clone())
+            .cookie_store(true)
+            .timeout(self.timeout)
+            .user_agent(&self.user_agent)
+            .gzip(self.compression)
+            .brotli(self.compression);
+        if let Some(connect_timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+        if self.no_proxy {
+            client_builder = client_builder.no_proxy();
+        }
+        for proxy in &self.proxies {
+            client_builder = client_builder.proxy(proxy.clone());
+        }
+        client_builder.build().map_err(FplError::Client)
+    }
+
+    /// Builds the HTTP client with the user agent and default headers of this builder.
+    ///
+    /// In the browser, requests are made with the fetch API, which keeps the cookies and applies
+    /// its own timeouts, so only the headers can be configured.
+    #[cfg(target_arch = "wasm32")]
+    fn build_client(&self) -> Result<Client, FplError> {
+        let mut default_headers = self.default_headers.clone();
+        if let Ok(user_agent) = HeaderValue::from_str(&self.user_agent) {
+            default_headers.insert(USER_AGENT, user_agent);
+        }
+        ClientBuilder::new()
+            .default_headers(default_headers)
+            .build()
+            .map_err(FplError::Client)
+    }
+}
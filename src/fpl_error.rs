@@ -1,21 +1,176 @@
 use core::fmt;
 
+use std::time::Duration;
+
+use reqwest::StatusCode;
 
 #[derive(Debug)]
-pub struct FplError {
-    msg: String,
+pub enum FplError {
+    /// The request to the FPL API failed, or its response could not be read.
+    Request(reqwest::Error),
+    /// The request to the FPL API took longer than the configured timeout.
+    Timeout(reqwest::Error),
+    /// The HTTP client could not be built, such as when the TLS backend cannot be initialized.
+    Client(reqwest::Error),
+    /// The FPL API responded with an unexpected status code.
+    HttpStatus(StatusCode),
+    /// The FPL API blocked the request, answering with an HTML page instead of JSON.
+    Blocked(StatusCode),
+    /// The FPL API rejected the request, with the detail it gave.
+    Api { status: StatusCode, detail: String },
+    /// The response of the FPL API could not be deserialized into the expected type.
+    Deserialize(String),
+    /// The requested resource does not exist.
+    NotFound,
+    /// Too many requests have been made, with how long the FPL API asks to wait, if it does.
+    RateLimited { retry_after: Option<Duration> },
+    /// The data of a gameweek which has not started yet was requested.
+    NotYetAvailable { gameweek: i64 },
+    /// Players with these ids are not in the static data of the season.
+    UnknownPlayers { ids: Vec<i64> },
+    /// The game is being updated, which happens for a while after every deadline.
+    GameUpdating,
+    /// Too many requests to the FPL API have failed in a row, so the request was not made.
+    CircuitOpen,
+    /// An argument is invalid, so the request was not made.
+    InvalidArgument {
+        name: &'static str,
+        value: i64,
+        expected: String,
+    },
+    /// The credentials provided to log in were rejected.
+    InvalidCredentials,
+    /// The request requires a successful login, which has not been made.
+    AuthenticationRequired,
+    /// Any other error, described by its message.
+    Other(String),
+}
+
+impl FplError {
+    /// Returns the status code the FPL API responded with, if the error comes from a response.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            FplError::HttpStatus(status)
+            | FplError::Blocked(status)
+            | FplError::Api { status, .. } => Some(*status),
+            FplError::NotFound => Some(StatusCode::NOT_FOUND),
+            FplError::GameUpdating => Some(StatusCode::SERVICE_UNAVAILABLE),
+            FplError::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            FplError::Request(err) | FplError::Timeout(err) => err.status(),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the requested resource does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Returns whether the FPL API refused the request because too many requests were made.
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(StatusCode::TOO_MANY_REQUESTS)
+    }
 }
 
 impl fmt::Display for FplError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "FplError: {}", self.msg)
+        match self {
+            FplError::Request(err) => write!(f, "FplError: Failed when making request: {}", err),
+            FplError::Timeout(err) => write!(f, "FplError: Request timed out: {}", err),
+            FplError::Client(err) => {
+                write!(f, "FplError: Failed when building the Http client: {}", err)
+            }
+            FplError::HttpStatus(status) => {
+                write!(f, "FplError: Unexpected status code: {}", status)
+            }
+            FplError::Blocked(status) => write!(
+                f,
+                "FplError: Request blocked with status code: {}, an HTML page was returned instead of JSON. \
+                 Try setting another user agent with FplBuilder::user_agent or making fewer requests",
+                status
+            ),
+            FplError::Api { status, detail } => write!(
+                f,
+                "FplError: Request rejected with status code: {} and detail: {}",
+                status, detail
+            ),
+            FplError::Deserialize(msg) => write!(f, "FplError: {}", msg),
+            FplError::NotFound => write!(f, "FplError: Not found"),
+            FplError::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(
+                f,
+                "FplError: Too many requests, try again in {} seconds",
+                retry_after.as_secs()
+            ),
+            FplError::RateLimited { retry_after: None } => {
+                write!(f, "FplError: Too many requests, try again later")
+            }
+            FplError::NotYetAvailable { gameweek } => write!(
+                f,
+                "FplError: The data of gameweek {} is not available yet",
+                gameweek
+            ),
+            FplError::UnknownPlayers { ids } => {
+                write!(f, "FplError: Unknown players with ids: {:?}", ids)
+            }
+            FplError::GameUpdating => {
+                write!(f, "FplError: The game is being updated, try again later")
+            }
+            FplError::CircuitOpen => write!(
+                f,
+                "FplError: Too many requests to the FPL API have failed, try again later"
+            ),
+            FplError::InvalidArgument {
+                name,
+                value,
+                expected,
+            } => write!(
+                f,
+                "FplError: Invalid {}: {}, expected {}",
+                name, value, expected
+            ),
+            FplError::InvalidCredentials => write!(f, "FplError: Invalid login credentials"),
+            FplError::AuthenticationRequired => {
+                write!(f, "FplError: Login is required before making this request")
+            }
+            FplError::Other(msg) => write!(f, "FplError: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for FplError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FplError::Request(err) | FplError::Timeout(err) | FplError::Client(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for FplError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            return FplError::Timeout(err);
+        }
+        if !err.is_decode() {
+            return FplError::Request(err);
+        }
+        match err.url() {
+            Some(url) => FplError::Deserialize(format!(
+                "Failed when deserializing response from: {} with this error: {}",
+                url, err
+            )),
+            None => FplError::Deserialize(format!(
+                "Failed when deserializing response with this error: {}",
+                err
+            )),
+        }
     }
 }
 
 impl From<&str> for FplError {
     fn from(item: &str) -> Self {
-        FplError {
-            msg: item.to_string(),
-        }
+        FplError::Other(item.to_string())
     }
 }
@@ -1,31 +1,189 @@
+mod cache;
+pub mod circuit_breaker;
+mod conditional;
+#[cfg(not(target_arch = "wasm32"))]
+mod disk_cache;
+pub mod fpl_builder;
 pub mod fpl_error;
+pub mod metrics;
 pub mod models;
-
+#[cfg(not(target_arch = "wasm32"))]
+mod rate_limiter;
+mod retry_after;
+#[cfg(not(target_arch = "wasm32"))]
+mod retry_policy;
+pub mod request_observer;
+pub mod request_options;
+mod single_flight;
+mod static_data;
+use cache::Cache;
+use circuit_breaker::{CircuitBreaker, CircuitState};
+use conditional::{Conditional, Validators};
+#[cfg(not(target_arch = "wasm32"))]
+use disk_cache::{DiskCache, BOOTSTRAP_STATIC_FILE, FIXTURES_FILE};
+use fpl_builder::{BatchFailureMode, FplBuilder};
 use fpl_error::FplError;
+use futures::stream::{self, Stream, StreamExt};
+use metrics::{Metrics, MetricsSnapshot};
 use models::{
-    bootstrap_static::{BootstrapStatic, Event, Player, Players, Team},
-    classic_league::ClassicLeague,
+    bootstrap_static::{BootstrapStatic, Event, Player, Players, Position, Team},
+    classic_league::{ClassicLeague, NewEntries, NewEntry},
+    dream_team::DreamTeam,
+    event_status::EventStatus,
     fixture::{Fixture, Fixtures},
     gameweek::Gameweek,
-    h2h_league::H2HLeague,
+    h2h_league::{H2HLeague, H2HStandings},
+    me::Me,
+    my_team::{MyTeam, MyTeamPickUpdate},
+    player_filter::PlayerFilter,
+    player_summary::{PlayerSummaries, PlayerSummary},
+    set_piece_notes::{SetPieceNotes, TeamSetPieceNotes},
     transfer::Transfers,
+    transfer_payload::TransferPayload,
     user::User,
-    user_picks::UserPicks,
+    user_cup::UserCup,
+    user_history::UserHistory,
+    user_picks::{Pick, UserPicks},
 };
-use reqwest::{header::HeaderMap, Client, ClientBuilder};
+#[cfg(not(target_arch = "wasm32"))]
+use rate_limiter::RateLimiter;
+use request_observer::SharedObserver;
+use request_options::RequestOptions;
+use reqwest::{Client, RequestBuilder, Response};
+#[cfg(not(target_arch = "wasm32"))]
+use retry_policy::RetryPolicy;
 use serde::de::DeserializeOwned;
+use serde::Serialize;
+use single_flight::SingleFlight;
+use static_data::StaticData;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+/// The identifier of the overall classic league, which every entry belongs to.
+pub const OVERALL_LEAGUE_ID: i64 = 314;
+
+/// The number of entries on a page of classic league standings.
+pub const STANDINGS_PAGE_SIZE: i64 = 50;
+
+/// The most pages requested when retrieving every page of a league, to avoid endless loops.
+pub const MAX_PAGES: i64 = 100;
+
+/// How many gameweeks a season has, when the static data giving them is not cached.
+const GAMEWEEKS: i64 = 38;
+
+/// How many characters of a response body are kept in the error when it cannot be deserialized.
+const MAX_BODY_SNIPPET_CHARS: usize = 500;
+
+/// The longest time waited between two requests made while waiting for the game to be updated.
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_GAME_UPDATE_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The longest time asked by a `Retry-After` header which is waited before retrying a request.
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
 
 /// Fantasy Premier League API Wrapper
 ///
 /// The `Fpl` struct represents a wrapper for interacting with the Fantasy Premier League (FPL) API.
 /// It provides methods for retrieving various data such as player details, team information, gameweek details, and more.
-#[derive(Debug)]
+#[derive(Clone)]
 pub struct Fpl {
-    /// An optional field containing static data fetched from the FPL API.
-    /// It is set to `None` initially and is populated with data whenever a request requiring static information is made.
-    bootstrap_static: Option<BootstrapStatic>,
+    /// The static data fetched from the FPL API, shared with the clones of this instance.
+    /// It is empty initially and is populated with data whenever a request requiring static information is made.
+    bootstrap_static: Arc<Cache<Arc<StaticData>>>,
+    /// How long the static data is used before being fetched again.
+    bootstrap_ttl: Duration,
+    /// The fixtures fetched last, reused for the fixtures which are finished, shared with the clones of this instance.
+    fixtures: Arc<Cache<Fixtures>>,
+    /// The directory the static data and fixtures are saved to, if any.
+    #[cfg(not(target_arch = "wasm32"))]
+    disk_cache: Option<DiskCache>,
+    /// The requests for the live data of a gameweek in flight, by gameweek id, shared with the clones of this instance.
+    live_gameweeks: Arc<SingleFlight<i64, Gameweek>>,
+    /// How many requests a batch method makes at once.
+    concurrency: usize,
+    /// What a batch method does when one of its requests fails.
+    batch_failure_mode: BatchFailureMode,
     /// An instance of an HTTP client used to make requests to the FPL API.
+    /// It keeps the session cookies set when logging in.
     http_client: Client,
+    /// Whether a successful login has been made with this instance.
+    authenticated: bool,
+    /// The URL every FPL API request is made relative to, ending with a `/`.
+    base_url: String,
+    /// The URL of the login page the credentials are posted to.
+    login_url: String,
+    /// The observer of every request made, if any.
+    observer: Option<SharedObserver>,
+    /// The counters of the requests made, shared with the clones of this instance, if enabled.
+    metrics: Option<Arc<Metrics>>,
+    /// The circuit breaker failing requests at once after too many have failed, shared with the
+    /// clones of this instance, if enabled.
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    /// The limit of requests made, shared with the clones of this instance, if any.
+    #[cfg(not(target_arch = "wasm32"))]
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// How requests failing transiently are retried.
+    #[cfg(not(target_arch = "wasm32"))]
+    retry_policy: RetryPolicy,
+    /// How long a request waits for the game to be updated, if it does.
+    #[cfg(not(target_arch = "wasm32"))]
+    game_update_wait: Option<Duration>,
+}
+
+impl Default for Fpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Fpl {
+    /// Formats the instance without the cached static data, which is several megabytes large,
+    /// showing only whether it is cached and how many players and teams it holds.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Fpl");
+        let counts = self.bootstrap_static.read(|bootstrap_static, _| {
+            (
+                bootstrap_static.elements.len(),
+                bootstrap_static.teams.len(),
+            )
+        });
+        match counts {
+            Some((players, teams)) => debug
+                .field("bootstrap_static_cached", &true)
+                .field("players", &players)
+                .field("teams", &teams),
+            None => debug.field("bootstrap_static_cached", &false),
+        };
+        debug
+            .field("bootstrap_ttl", &self.bootstrap_ttl)
+            .field(
+                "fixtures",
+                &self.fixtures.read(|fixtures, _| fixtures.len()),
+            )
+            .field("concurrency", &self.concurrency)
+            .field("batch_failure_mode", &self.batch_failure_mode)
+            .field("http_client", &self.http_client)
+            .field("authenticated", &self.authenticated)
+            .field("base_url", &self.base_url)
+            .field("login_url", &self.login_url)
+            .field("observer", &self.observer)
+            .field("metrics", &self.metrics)
+            .field("circuit_breaker", &self.circuit_breaker);
+        #[cfg(not(target_arch = "wasm32"))]
+        debug
+            .field("rate_limiter", &self.rate_limiter)
+            .field("retry_policy", &self.retry_policy)
+            .field("disk_cache", &self.disk_cache)
+            .field("game_update_wait", &self.game_update_wait);
+        debug.finish()
+    }
 }
 
 impl Fpl {
@@ -33,7 +191,12 @@ impl Fpl {
     ///
     /// # Returns
     ///
-    /// A new instance of the `Fpl` API wrapper.
+    /// A new instance of the `Fpl` API wrapper, with requests timing out after 30 seconds.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the HTTP client cannot be built, use
+    /// [`try_new`](struct.Fpl.html#method.try_new) to handle this error instead.
     ///
     /// # Examples
     ///
@@ -49,15 +212,68 @@ impl Fpl {
     /// }
     /// ```
     pub fn new() -> Fpl {
-        let default_headers = HeaderMap::new();
-        let http_client = ClientBuilder::new()
-            .default_headers(default_headers)
-            .build()
-            .expect("Failed to build Http client");
-        Fpl {
-            bootstrap_static: None,
-            http_client,
-        }
+        Fpl::try_new().expect("Failed to build Http client")
+    }
+
+    /// Creates a new instance of the `Fpl` API wrapper, returning an error instead of panicking
+    /// if it cannot be created.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a new instance of the `Fpl` API wrapper, with requests timing out
+    /// after 30 seconds, on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `FplError::Client`, wrapping the underlying error, if the HTTP client
+    /// cannot be built, such as when the TLS backend cannot be initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// fn main() {
+    ///     match Fpl::try_new() {
+    ///         Ok(_fpl) => {
+    ///             // Use the Fpl instance to make API requests
+    ///             // ...
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn try_new() -> Result<Fpl, FplError> {
+        FplBuilder::new().build()
+    }
+
+    /// Creates a builder to configure a new instance of the `Fpl` API wrapper.
+    ///
+    /// # Returns
+    ///
+    /// A new `FplBuilder` with the default configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use fpl_rs::{fpl_error::FplError, Fpl};
+    ///
+    /// fn main() -> Result<(), FplError> {
+    ///     // Create a new Fpl instance which gives up on requests after 10 seconds
+    ///     let fpl = Fpl::builder().timeout(Duration::from_secs(10)).build()?;
+    ///
+    ///     // Use the Fpl instance to make API requests
+    ///     // ...
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn builder() -> FplBuilder {
+        FplBuilder::new()
     }
 
     /// Asynchronously fetches data from the specified URL and deserializes it into the provided type.
@@ -73,9 +289,16 @@ impl Fpl {
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
-    /// - If there is a failure when making the request to the specified URL.
-    /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the specified type.
+    /// - `FplError::Request` if there is a failure when making the request to the specified URL.
+    /// - `FplError::Timeout` if the request takes longer than the configured timeout.
+    /// - `FplError::Blocked` if the FPL API answers with an HTML page and the status code OK (200), FORBIDDEN (403)
+    ///   or TOO MANY REQUESTS (429), which it does when blocking requests.
+    /// - `FplError::NotFound` if the HTTP response status code is NOT FOUND (404) and the FPL API does not tell what was not found.
+    /// - `FplError::GameUpdating` if the FPL API answers that the game is being updated.
+    /// - `FplError::RateLimited` if the HTTP response status code is TOO MANY REQUESTS (429).
+    /// - `FplError::Api` if the HTTP response status code is any other than OK (200) and the FPL API explains why.
+    /// - `FplError::HttpStatus` if the HTTP response status code is any other than OK (200) otherwise.
+    /// - `FplError::Deserialize` if there is an error deserializing the JSON response into the specified type.
     ///
     /// # Panics
     ///
@@ -89,48 +312,383 @@ impl Fpl {
     where
         T: DeserializeOwned,
     {
-        let error_message = format!("Failed when making request to: {}", url);
-        let response = match self.http_client.get(url).send().await {
-            Ok(r) => r,
-            Err(err) => {
-                let error_message = format!("{} with this error: {}", error_message, err);
-                return Err(FplError::from(error_message.as_str()));
+        self.fetch_with_options(url, &RequestOptions::default())
+            .await
+    }
+
+    /// Asynchronously fetches data from the specified URL with options overriding the
+    /// configuration of this instance, and deserializes it into the provided type.
+    ///
+    /// See [`fetch`](struct.Fpl.html#method.fetch), which this function behaves like.
+    async fn fetch_with_options<T>(
+        &self,
+        url: String,
+        options: &RequestOptions,
+    ) -> Result<T, FplError>
+    where
+        T: DeserializeOwned,
+    {
+        match self.fetch_conditional(url, options, None).await? {
+            Conditional::Modified(value, _) => Ok(value),
+            // Only conditional requests are answered with `304 Not Modified`.
+            Conditional::NotModified => {
+                Err(FplError::HttpStatus(reqwest::StatusCode::NOT_MODIFIED))
             }
+        }
+    }
+
+    /// Asynchronously fetches data from the specified URL, deserializing it into the provided type,
+    /// unless it has not changed since `validators` were sent, if there are any.
+    ///
+    /// See [`fetch`](struct.Fpl.html#method.fetch), which this function behaves like, except for
+    /// returning the validators of the response, which make the next request for the URL conditional.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "fpl.fetch",
+            skip_all,
+            fields(path = url.strip_prefix(self.base_url.as_str()).unwrap_or(&url))
+        )
+    )]
+    async fn fetch_conditional<T>(
+        &self,
+        url: String,
+        options: &RequestOptions,
+        validators: Option<&Validators>,
+    ) -> Result<Conditional<T>, FplError>
+    where
+        T: DeserializeOwned,
+    {
+        let Some(circuit_breaker) = &self.circuit_breaker else {
+            return self.fetch_until_updated(&url, options, validators).await;
         };
-        match response.status() {
-            reqwest::StatusCode::OK => match response.json::<T>().await {
-                Ok(parsed) => Ok(parsed),
-                Err(err) => {
-                    let error_message = format!("{} with this error: {}", error_message, err);
-                    Err(FplError::from(error_message.as_str()))
+        circuit_breaker.acquire()?;
+        let result = self.fetch_until_updated(&url, options, validators).await;
+        circuit_breaker.record(&result);
+        result
+    }
+
+    /// Asynchronously fetches data from the specified URL, waiting for the game to be updated if
+    /// this instance is configured to, and deserializes it into the provided type.
+    ///
+    /// See [`fetch_conditional`](struct.Fpl.html#method.fetch_conditional), which this function
+    /// behaves like, except for the circuit breaker.
+    async fn fetch_until_updated<T>(
+        &self,
+        url: &str,
+        options: &RequestOptions,
+        validators: Option<&Validators>,
+    ) -> Result<Conditional<T>, FplError>
+    where
+        T: DeserializeOwned,
+    {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(wait) = self.game_update_wait {
+            let deadline = Instant::now() + wait;
+            let poll_interval = (wait / 10).min(MAX_GAME_UPDATE_POLL_INTERVAL);
+            loop {
+                match self.fetch_once(url, options, validators).await {
+                    Err(FplError::GameUpdating) if Instant::now() + poll_interval < deadline => {
+                        tokio::time::sleep(poll_interval).await;
+                    }
+                    result => return result,
                 }
-            },
+            }
+        }
+        self.fetch_once(url, options, validators).await
+    }
+
+    /// Asynchronously fetches data from the specified URL once, and deserializes it into the provided type.
+    ///
+    /// See [`fetch_conditional`](struct.Fpl.html#method.fetch_conditional), which this function
+    /// behaves like, except for waiting for the game to be updated.
+    async fn fetch_once<T>(
+        &self,
+        url: &str,
+        options: &RequestOptions,
+        validators: Option<&Validators>,
+    ) -> Result<Conditional<T>, FplError>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self
+            .execute_with_retries(|| {
+                let mut request = self.http_client.get(url);
+                if let Some(validators) = validators {
+                    request = validators.apply(request);
+                }
+                // The fetch API of the browser has no per-request timeout.
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(timeout) = options.timeout {
+                    request = request.timeout(timeout);
+                }
+                #[cfg(target_arch = "wasm32")]
+                let _ = options;
+                request
+            })
+            .await?;
+        // Blocked requests are answered with an HTML page, either an error or a challenge, which would
+        // otherwise fail to deserialize. Other HTML pages, such as the errors of a load balancer, are not blocks.
+        let is_html = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .is_some_and(|content_type| content_type.starts_with("text/html"));
+        if response.status() == reqwest::StatusCode::SERVICE_UNAVAILABLE {
+            let status = response.status();
+            let body = self.read_body(response).await?;
+            if is_game_updating(&body) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(%status, "FPL API is being updated");
+                return Err(FplError::GameUpdating);
+            }
+            #[cfg(feature = "tracing")]
+            tracing::warn!(%status, "FPL API request failed");
+            return Err(FplError::HttpStatus(status));
+        }
+        let is_block = matches!(
+            response.status(),
+            reqwest::StatusCode::OK
+                | reqwest::StatusCode::FORBIDDEN
+                | reqwest::StatusCode::TOO_MANY_REQUESTS
+        );
+        if is_html && is_block {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(status = %response.status(), "FPL API request blocked");
+            return Err(FplError::Blocked(response.status()));
+        }
+        match response.status() {
+            reqwest::StatusCode::OK => {
+                let url = response.url().to_string();
+                let validators = Validators::from_response(&response);
+                let body = self.read_body(response).await?;
+                deserialize_body(&url, &body).map(|value| Conditional::Modified(value, validators))
+            }
+            reqwest::StatusCode::NOT_MODIFIED if validators.is_some() => {
+                Ok(Conditional::NotModified)
+            }
+            reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(status = %reqwest::StatusCode::TOO_MANY_REQUESTS, "FPL API request failed");
+                Err(FplError::RateLimited {
+                    retry_after: retry_after::retry_after(&response),
+                })
+            }
+            reqwest::StatusCode::NOT_FOUND => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(status = %reqwest::StatusCode::NOT_FOUND, "FPL API request failed");
+                let body = self.read_body(response).await?;
+                // The detail is only worth keeping when it tells what was not found.
+                Err(match error_detail(&body) {
+                    Some(detail) if detail != "Not found." => FplError::Api {
+                        status: reqwest::StatusCode::NOT_FOUND,
+                        detail,
+                    },
+                    _ => FplError::NotFound,
+                })
+            }
             other_status_code => {
-                let error_message = format!(
-                    "{} with this status code: {}",
-                    error_message, other_status_code
-                );
-                Err(FplError::from(error_message.as_str()))
+                #[cfg(feature = "tracing")]
+                tracing::warn!(status = %other_status_code, "FPL API request failed");
+                let body = self.read_body(response).await?;
+                Err(match error_detail(&body) {
+                    Some(detail) => FplError::Api {
+                        status: other_status_code,
+                        detail,
+                    },
+                    None => FplError::HttpStatus(other_status_code),
+                })
             }
         }
     }
 
-    /// Asynchronously retrieves information about a Fantasy Premier League user.
+    /// Checks that the identifier of a user, league or player is positive.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `FplError::InvalidArgument` if the identifier is not positive.
+    fn check_id(name: &'static str, value: i64) -> Result<(), FplError> {
+        if value > 0 {
+            return Ok(());
+        }
+        Err(FplError::InvalidArgument {
+            name,
+            value,
+            expected: String::from("a positive id"),
+        })
+    }
+
+    /// Checks that a gameweek id is between 1 and the last gameweek of the season, given by the
+    /// cached static data when there is any, or 38 otherwise.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `FplError::InvalidArgument` if the id is out of bounds.
+    fn check_gameweek_id(&self, gameweek_id: i64) -> Result<(), FplError> {
+        let last_gameweek_id = self
+            .bootstrap_static
+            .read(|bootstrap_static, _| bootstrap_static.events.iter().map(|event| event.id).max())
+            .flatten()
+            .unwrap_or(GAMEWEEKS);
+        if (1..=last_gameweek_id).contains(&gameweek_id) {
+            return Ok(());
+        }
+        Err(FplError::InvalidArgument {
+            name: "gameweek_id",
+            value: gameweek_id,
+            expected: format!("a gameweek id between 1 and {}", last_gameweek_id),
+        })
+    }
+
+    /// Asynchronously sends the request built by `request`, building and sending it again
+    /// while it fails transiently and the retry policy of this instance allows it.
+    ///
+    /// When too many requests have been made, the request is also sent again once after the time
+    /// the FPL API asks to wait in its `Retry-After` header, unless that is over a minute.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `reqwest::Error` if the last attempt cannot be built or sent.
+    async fn execute_with_retries<F>(&self, request: F) -> Result<Response, reqwest::Error>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let mut attempt = 0;
+            let mut waited_retry_after = false;
+            loop {
+                let result = self.execute(request()).await;
+                let retry_after = result
+                    .as_ref()
+                    .ok()
+                    .filter(|response| response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS)
+                    .and_then(retry_after::retry_after);
+                let delay = match retry_after {
+                    Some(retry_after) if retry_after > MAX_RETRY_AFTER => return result,
+                    Some(retry_after) if !waited_retry_after => {
+                        waited_retry_after = true;
+                        retry_after
+                    }
+                    _ if attempt < self.retry_policy.max_retries
+                        && RetryPolicy::is_retryable(&result) =>
+                    {
+                        attempt += 1;
+                        self.retry_policy
+                            .delay(attempt - 1)
+                            .max(retry_after.unwrap_or_default())
+                    }
+                    _ => return result,
+                };
+                #[cfg(feature = "tracing")]
+                tracing::debug!(?delay, "retrying FPL API request");
+                tokio::time::sleep(delay).await;
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        self.execute(request()).await
+    }
+
+    /// Asynchronously sends a request once the rate limit of this instance allows it, reporting it
+    /// to the observer and the metrics of this instance if there are any.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `reqwest::Error` if the request cannot be built or sent.
+    async fn execute(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        if self.observer.is_none() && self.metrics.is_none() {
+            return request.send().await;
+        }
+        let request = request.build()?;
+        let url = request.url().to_string();
+        if let Some(SharedObserver(observer)) = &self.observer {
+            observer.before(&url);
+        }
+        let start = Instant::now();
+        let result = self.http_client.execute(request).await;
+        let elapsed = start.elapsed();
+        let status = result.as_ref().ok().map(|response| response.status());
+        if let Some(metrics) = &self.metrics {
+            metrics.record(status, elapsed);
+        }
+        if let Some(SharedObserver(observer)) = &self.observer {
+            observer.after(&url, status, elapsed);
+        }
+        result
+    }
+
+    /// Asynchronously reads the body of `response`, counting its size in the metrics of this instance if there are any.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `reqwest::Error` if the body cannot be read or decompressed.
+    async fn read_body(&self, response: Response) -> Result<String, reqwest::Error> {
+        let body = response.text().await?;
+        if let Some(metrics) = &self.metrics {
+            metrics.record_body(body.len());
+        }
+        Ok(body)
+    }
+
+    /// Asynchronously posts a JSON body to the specified URL.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `FplError` if the request fails, or with `FplError::Api`
+    /// holding the detail given by the FPL API if it rejects the request.
+    async fn post<B>(&self, url: String, body: &B) -> Result<(), FplError>
+    where
+        B: Serialize + ?Sized,
+    {
+        self.send(self.http_client.post(url).json(body)).await
+    }
+
+    /// Asynchronously makes a DELETE request to the specified URL.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `FplError` if the request fails, or with `FplError::Api`
+    /// holding the detail given by the FPL API if it rejects the request.
+    async fn delete(&self, url: String) -> Result<(), FplError> {
+        self.send(self.http_client.delete(url)).await
+    }
+
+    /// Asynchronously sends a request which is not expected to return any data.
+    async fn send(&self, request: RequestBuilder) -> Result<(), FplError> {
+        let response = self.execute(request).await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        #[cfg(feature = "tracing")]
+        tracing::warn!(%status, "FPL API rejected the request");
+        let body = self.read_body(response).await?;
+        let detail = error_detail(&body).unwrap_or(body);
+        Err(FplError::Api { status, detail })
+    }
+
+    /// Asynchronously retrieves the JSON response of any Fantasy Premier League API endpoint.
+    ///
+    /// This is useful to reach endpoints, or fields, which this crate does not support yet.
     ///
     /// # Arguments
     ///
-    /// * `user_id` - An `i64` representing the unique identifier of the FPL user.
+    /// * `path` - A `&str` containing the path of the endpoint, relative to the base URL, such as `event-status/`.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with user information on success, or an `FplError` on failure.
+    /// Returns a `Result` with the JSON response on success, or an `FplError` on failure.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `User` type.
+    /// - If there is an error deserializing the JSON response into the `serde_json::Value` type.
     ///
     /// # Examples
     ///
@@ -140,12 +698,11 @@ impl Fpl {
     /// #[tokio::main]
     /// async fn main() {
     ///     let fpl = Fpl::new();
-    ///     let user_id = 12345;
     ///
-    ///     match fpl.get_user(user_id).await {
-    ///         Ok(user) => {
-    ///             // Process the user information
-    ///             println!("{:?}", user);
+    ///     match fpl.get_raw("event-status/").await {
+    ///         Ok(json) => {
+    ///             // Process the JSON response
+    ///             println!("{:?}", json);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -158,7 +715,7 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `user_id` should be a valid identifier of an existing FPL user.
+    /// The request is made like those of the other functions, with the same headers and error handling.
     ///
     /// # Panics
     ///
@@ -167,43 +724,60 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted user IDs or relying on FPL API data.
+    /// Use caution when passing untrusted paths or relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_user(&self, user_id: i64) -> Result<User, FplError> {
-        let url = format!("https://fantasy.premierleague.com/api/entry/{}/", user_id);
-        return self.fetch(url).await;
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_raw", skip(self))
+    )]
+    pub async fn get_raw(&self, path: &str) -> Result<serde_json::Value, FplError> {
+        self.get_typed(path).await
     }
 
-    /// Asynchronously retrieves information about Fantasy Premier League fixtures.
+    /// Asynchronously retrieves the response of any Fantasy Premier League API endpoint, deserialized into the provided type.
+    ///
+    /// This is useful to reach endpoints which this crate does not support yet, or to deserialize
+    /// responses into custom types.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A `&str` containing the path of the endpoint, relative to the base URL, such as `event-status/`.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with fixtures information on success, or an `FplError` on failure.
+    /// Returns a `Result` with the deserialized response on success, or an `FplError` on failure.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Fixtures` type.
+    /// - If there is an error deserializing the JSON response into the `T` type.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use fpl_rs::Fpl;
     ///
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct Leagues {
+    ///     leagues: String,
+    /// }
+    ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let fpl = Fpl::new();
     ///
-    ///     match fpl.get_fixtures().await {
-    ///         Ok(fixtures) => {
-    ///             // Process the user information
-    ///             println!("{:?}", fixtures);
+    ///     match fpl.get_typed::<Leagues>("event-status/").await {
+    ///         Ok(leagues) => {
+    ///             // Process the deserialized response
+    ///             println!("{:?}", leagues);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -216,6 +790,7 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The request is made like those of the other functions, with the same headers and error handling.
     ///
     /// # Panics
     ///
@@ -224,48 +799,57 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted user IDs or relying on FPL API data.
+    /// Use caution when passing untrusted paths or relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_fixtures(&self) -> Result<Fixtures, FplError> {
-        let url = String::from("https://fantasy.premierleague.com/api/fixtures/");
-        return self.fetch(url).await;
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_typed", skip(self))
+    )]
+    pub async fn get_typed<T>(&self, path: &str) -> Result<T, FplError>
+    where
+        T: DeserializeOwned,
+    {
+        let url = format!("{}{}", self.base_url, path.trim_start_matches('/'));
+        self.fetch(url).await
     }
 
-    /// Asynchronously retrieves information about a Fantasy Premier League gameweek fixtures.
+    /// Asynchronously logs in to Fantasy Premier League with the provided credentials.
+    ///
+    /// On success, the session cookies are kept by the HTTP client of this instance and sent
+    /// with every subsequent request, giving access to the endpoints that require a logged in user.
     ///
     /// # Arguments
     ///
-    /// * `gameweek_id` - An `i64` representing the a gameweek (from 1 to 38)
+    /// * `email` - A `&str` containing the email address of the FPL account.
+    /// * `password` - A `&str` containing the password of the FPL account.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with fixture information on success, or an `FplError` on failure.
+    /// Returns a `Result` with `()` on success, or an `FplError` on failure.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
-    /// - If there is a failure when making the request to the FPL API.
-    /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `User` type.
+    /// - If there is a failure when making the request to the FPL login page.
+    /// - If the provided credentials are rejected, with `FplError::InvalidCredentials`.
     ///
     /// # Examples
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use fpl_rs::Fpl;
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let fpl = Fpl::new();
-    ///     let gameweek_id = 1;
+    ///     let mut fpl = Fpl::new();
     ///
-    ///     match fpl.get_gameweek_fixtures(gameweek_id).await {
-    ///         Ok(gameweek_fixtures) => {
-    ///             // Process the gameweek fixtures
-    ///             println!("{:?}", gameweek_fixtures);
+    ///     match fpl.login("email@example.com", "password").await {
+    ///         Ok(()) => {
+    ///             // Make requests that require a logged in user
+    ///             println!("{}", fpl.is_authenticated());
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -277,46 +861,86 @@ impl Fpl {
     ///
     /// # Note
     ///
-    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `gameweek_id` should be a valid identifier of a gameweek.
-    ///
-    /// # Panics
-    ///
-    /// This function may panic if there is an internal error during HTTP request processing.
-    ///
-    /// # Safety
-    ///
-    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted user IDs or relying on FPL API data.
-    ///
-    /// # See Also
-    ///
-    /// - [`fetch`](struct.Fpl.html#method.fetch)
-    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_gameweek_fixtures(&self, gameweek_id: i64) -> Result<Fixtures, FplError> {
-        let url = format!(
-            "https://fantasy.premierleague.com/api/fixtures/?event={}",
-            gameweek_id
-        );
-        return self.fetch(url).await;
+    /// The credentials are only sent to the FPL login page. They are never stored,
+    /// neither in memory nor on disk, only the resulting session cookies are kept in memory.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.login", skip(self, password))
+    )]
+    pub async fn login(&mut self, email: &str, password: &str) -> Result<(), FplError> {
+        let url = &self.login_url;
+        let form = [
+            ("login", email),
+            ("password", password),
+            ("app", "plfpl-web"),
+            ("redirect_uri", "https://fantasy.premierleague.com/"),
+        ];
+        let response = self.execute(self.http_client.post(url).form(&form)).await?;
+        // The login page redirects to the redirect URI with the outcome in the `state` parameter.
+        let state = response
+            .url()
+            .query_pairs()
+            .find(|(key, _)| key == "state")
+            .map(|(_, value)| value.into_owned());
+        self.authenticated = state.as_deref() == Some("success");
+        match state.as_deref() {
+            Some("success") => Ok(()),
+            Some(_) => Err(FplError::InvalidCredentials),
+            None => {
+                let error_message = format!(
+                    "Failed when logging in to: {} with this status code: {}",
+                    url,
+                    response.status()
+                );
+                Err(FplError::from(error_message.as_str()))
+            }
+        }
     }
 
-    /// Asynchronously retrieves information about a Fantasy Premier League fixture.
+    /// Returns whether a successful login has been made with this instance.
+    pub fn is_authenticated(&self) -> bool {
+        self.authenticated
+    }
+
+    /// Returns the counters of the requests made with this instance and its clones.
+    ///
+    /// The counters stay at zero unless enabled with
+    /// [`FplBuilder::collect_metrics`](fpl_builder/struct.FplBuilder.html#method.collect_metrics).
+    pub fn metrics(&self) -> MetricsSnapshot {
+        self.metrics
+            .as_ref()
+            .map(|metrics| metrics.snapshot())
+            .unwrap_or_default()
+    }
+
+    /// Returns the state of the circuit breaker of this instance and its clones.
+    ///
+    /// The circuit stays closed unless the circuit breaker is enabled with
+    /// [`FplBuilder::circuit_breaker`](fpl_builder/struct.FplBuilder.html#method.circuit_breaker).
+    pub fn circuit_state(&self) -> CircuitState {
+        self.circuit_breaker
+            .as_ref()
+            .map(|circuit_breaker| circuit_breaker.state())
+            .unwrap_or(CircuitState::Closed)
+    }
+
+    /// Asynchronously retrieves information about a Fantasy Premier League user.
     ///
     /// # Arguments
     ///
-    /// * `fixture_id` - An `i64` representing the unique identifier of the FPL fixture.
+    /// * `user_id` - An `i64` representing the unique identifier of the FPL user.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with fixture information on success, or an `FplError` on failure.
+    /// Returns a `Result` with user information on success, or an `FplError` on failure.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
+    /// - If `user_id` is not positive, with `FplError::InvalidArgument`.
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Fixture` type.
+    /// - If there is an error deserializing the JSON response into the `User` type.
     ///
     /// # Examples
     ///
@@ -325,13 +949,13 @@ impl Fpl {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut fpl = Fpl::new();
-    ///     let fixture_id = 12;
+    ///     let fpl = Fpl::new();
+    ///     let user_id = 12345;
     ///
-    ///     match fpl.get_fixture(fixture_id).await {
-    ///         Ok(fixture) => {
-    ///             // Process the fixture information
-    ///             println!("{:?}", fixture);
+    ///     match fpl.get_user(user_id).await {
+    ///         Ok(user) => {
+    ///             // Process the user information
+    ///             println!("{:?}", user);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -344,7 +968,7 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `fixture_id` should be a valid identifier of an FPL fixture.
+    /// The provided `user_id` should be a valid identifier of an existing FPL user.
     ///
     /// # Panics
     ///
@@ -359,46 +983,36 @@ impl Fpl {
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_fixture(&mut self, fixture_id: i64) -> Result<Fixture, FplError> {
-        let all_fixtures = self.get_fixtures().await?;
-        let gameweek_id = all_fixtures
-            .into_iter()
-            .filter(|fixture| fixture.id == fixture_id)
-            .collect::<Fixtures>()
-            .first()
-            .expect("Failed when parsing fixtures response.")
-            .event
-            .expect("Failed when parsing fixtures response.");
-
-        let gameweek_fixtures = self.get_gameweek_fixtures(gameweek_id).await?;
-
-        let fixture = gameweek_fixtures
-            .into_iter()
-            .filter(|fixture| fixture.id == fixture_id)
-            .collect::<Fixtures>();
-
-        match fixture.first() {
-            Some(f) => Ok(f.clone()),
-            None => return Err(FplError::from("Failed when parsing fixtures response.")),
-        }
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_user", skip(self))
+    )]
+    pub async fn get_user(&self, user_id: i64) -> Result<User, FplError> {
+        Self::check_id("user_id", user_id)?;
+        let url = format!("{}entry/{}/", self.base_url, user_id);
+        self.fetch(url).await
     }
 
-    /// Asynchronously retrieves information about a Fantasy Premier League gameweek.
+    /// Asynchronously retrieves the season history of a Fantasy Premier League user.
+    ///
+    /// The history contains the user's results for every gameweek of the current season,
+    /// their final results for previous seasons and the chips they have played.
     ///
     /// # Arguments
     ///
-    /// * `gameweek_id` - An `i64` representing the gameweek.
+    /// * `user_id` - An `i64` representing the unique identifier of the FPL user.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with gameweek information on success, or an `FplError` on failure.
+    /// Returns a `Result` with the user's history on success, or an `FplError` on failure.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
+    /// - If `user_id` is not positive, with `FplError::InvalidArgument`.
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Event` type.
+    /// - If there is an error deserializing the JSON response into the `UserHistory` type.
     ///
     /// # Examples
     ///
@@ -407,21 +1021,18 @@ impl Fpl {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut fpl = Fpl::new();
-    ///     let gameweek_id = 12345;
+    ///     let fpl = Fpl::new();
+    ///     let user_id = 12345;
     ///
-    ///     match fpl.get_static_gameweek(gameweek_id).await {
-    ///         Ok(Some(gameweek)) => {
-    ///                 // Process the gameweek information
-    ///                 println!("{:?}", gameweek);
-    ///         },
-    ///             Ok(None) => {
-    ///                 eprintln!("Got nothing!");
-    ///             }
+    ///     match fpl.get_user_history(user_id).await {
+    ///         Ok(user_history) => {
+    ///             // Process the user's history
+    ///             println!("{:?}", user_history);
+    ///         }
     ///         Err(err) => {
     ///             // Handle the error
     ///             eprintln!("Error: {}", err);
-    ///         },
+    ///         }
     ///     }
     /// }
     /// ```
@@ -429,7 +1040,7 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `gameweek_id` should be a valid gameweek.
+    /// The provided `user_id` should be a valid identifier of an existing FPL user.
     ///
     /// # Panics
     ///
@@ -444,35 +1055,47 @@ impl Fpl {
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_static_gameweek(
-        &mut self,
-        gameweek_id: i64,
-    ) -> Result<Option<Event>, FplError> {
-        let all_gameweeks = self.get_static_gameweeks().await?;
-        return Ok(all_gameweeks
-            .into_iter()
-            .filter(|gameweek| gameweek_id == gameweek.id)
-            .collect::<Vec<Event>>()
-            .first()
-            .cloned());
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_user_history", skip(self))
+    )]
+    pub async fn get_user_history(&self, user_id: i64) -> Result<UserHistory, FplError> {
+        Self::check_id("user_id", user_id)?;
+        let url = format!("{}entry/{}/history/", self.base_url, user_id);
+        self.fetch(url).await
     }
 
-    /// Asynchronously retrieves live data for a specific Fantasy Premier League gameweek.
+    /// Asynchronously retrieves the season history of a Fantasy Premier League user.
+    ///
+    /// This is an alias of [`get_user_history`](struct.Fpl.html#method.get_user_history),
+    /// named after the `entry/{user_id}/history/` endpoint it wraps.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_entry_history", skip(self))
+    )]
+    pub async fn get_entry_history(&self, user_id: i64) -> Result<UserHistory, FplError> {
+        self.get_user_history(user_id).await
+    }
+
+    /// Asynchronously retrieves the cup status and cup matches of a Fantasy Premier League user.
     ///
     /// # Arguments
     ///
-    /// * `gameweek_id` - An `i64` representing the unique identifier of the FPL gameweek.
+    /// * `user_id` - An `i64` representing the unique identifier of the FPL user.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with live gameweek data on success, or an `FplError` on failure.
+    /// Returns a `Result` with the user's cup information on success, or an `FplError` on failure.
+    ///
+    /// Before the cup starts, the list of matches is empty.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
+    /// - If `user_id` is not positive, with `FplError::InvalidArgument`.
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Gameweek` type.
+    /// - If there is an error deserializing the JSON response into the `UserCup` type.
     ///
     /// # Examples
     ///
@@ -482,12 +1105,12 @@ impl Fpl {
     /// #[tokio::main]
     /// async fn main() {
     ///     let fpl = Fpl::new();
-    ///     let gameweek_id = 5;
+    ///     let user_id = 12345;
     ///
-    ///     match fpl.get_live_gameweek(gameweek_id).await {
-    ///         Ok(gameweek) => {
-    ///             // Process the live gameweek data
-    ///             println!("{:?}", gameweek);
+    ///     match fpl.get_user_cup(user_id).await {
+    ///         Ok(user_cup) => {
+    ///             // Process the user's cup information
+    ///             println!("{:?}", user_cup);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -500,7 +1123,7 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `gameweek_id` should be a valid identifier of an existing FPL gameweek.
+    /// The provided `user_id` should be a valid identifier of an existing FPL user.
     ///
     /// # Panics
     ///
@@ -509,44 +1132,40 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted gameweek IDs or relying on FPL API data.
+    /// Use caution when passing untrusted user IDs or relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_live_gameweek(&self, gameweek_id: i64) -> Result<Gameweek, FplError> {
-        let url = format!(
-            "https://fantasy.premierleague.com/api/event/{}/live",
-            gameweek_id
-        );
-        return self.fetch(url).await;
-    }
-
-    pub async fn get_transfers(&self, user_id: i64) -> Result<Transfers, FplError> {
-        let url = format!(
-            "https://fantasy.premierleague.com/api/entry/{}/transfers",
-            user_id
-        );
-        return self.fetch(url).await;
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_user_cup", skip(self))
+    )]
+    pub async fn get_user_cup(&self, user_id: i64) -> Result<UserCup, FplError> {
+        Self::check_id("user_id", user_id)?;
+        let url = format!("{}entry/{}/cup/", self.base_url, user_id);
+        self.fetch(url).await
     }
 
-    /// Asynchronously retrieves standings data for a Fantasy Premier League classic league.
+    /// Asynchronously retrieves information about Fantasy Premier League fixtures.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `league_id` - An `i64` representing the unique identifier of the FPL classic league.
+    /// Returns a `Result` with fixtures information on success, or an `FplError` on failure.
     ///
-    /// # Returns
+    /// Fixtures that have not been scheduled yet, such as postponed ones, have their `event` set to `None`.
     ///
-    /// Returns a `Result` with classic league standings on success, or an `FplError` on failure.
+    /// The fixtures are cached, but since their scores and kickoff times change until they are finished,
+    /// the cached copy is only reused once every fixture is finished. Otherwise they are fetched again,
+    /// see [`refresh_fixtures`](struct.Fpl.html#method.refresh_fixtures), conditionally on them having changed.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `ClassicLeague` type.
+    /// - If there is an error deserializing the JSON response into the `Fixtures` type.
     ///
     /// # Examples
     ///
@@ -556,12 +1175,11 @@ impl Fpl {
     /// #[tokio::main]
     /// async fn main() {
     ///     let fpl = Fpl::new();
-    ///     let league_id = 98765;
     ///
-    ///     match fpl.get_classic_league(league_id).await {
-    ///         Ok(league) => {
-    ///             // Process the classic league standings
-    ///             println!("{:?}", league);
+    ///     match fpl.get_fixtures().await {
+    ///         Ok(fixtures) => {
+    ///             // Process the user information
+    ///             println!("{:?}", fixtures);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -574,7 +1192,6 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `league_id` should be a valid identifier of an existing FPL classic league.
     ///
     /// # Panics
     ///
@@ -583,36 +1200,40 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    /// Use caution when passing untrusted user IDs or relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_classic_league(&self, league_id: i64) -> Result<ClassicLeague, FplError> {
-        let url = format!(
-            "https://fantasy.premierleague.com/api/leagues-classic/{}/standings/",
-            league_id
-        );
-        return self.fetch(url).await;
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_fixtures", skip_all)
+    )]
+    pub async fn get_fixtures(&self) -> Result<Fixtures, FplError> {
+        self.fixtures
+            .get_or_fetch(
+                |fixtures, _| fixtures.iter().all(|fixture| fixture.finished),
+                |validators| self.fetch_fixtures(validators),
+            )
+            .await
     }
 
-    /// Asynchronously retrieves standings data for a Fantasy Premier League head to head league.
-    ///
-    /// # Arguments
+    /// Asynchronously fetches the Fantasy Premier League fixtures, ignoring any cached copy.
     ///
-    /// * `league_id` - An `i64` representing the unique identifier of the FPL head to head league.
+    /// The fetched fixtures replace the cached copy, which [`get_fixture`](struct.Fpl.html#method.get_fixture)
+    /// and [`get_gameweek_fixtures`](struct.Fpl.html#method.get_gameweek_fixtures) reuse for finished fixtures.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with head to head league standings on success, or an `FplError` on failure.
+    /// Returns a `Result` with the freshly fetched fixtures on success, or an `FplError` on failure.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `H2HLeague` type.
+    /// - If there is an error deserializing the JSON response into the `Fixtures` type.
     ///
     /// # Examples
     ///
@@ -622,12 +1243,11 @@ impl Fpl {
     /// #[tokio::main]
     /// async fn main() {
     ///     let fpl = Fpl::new();
-    ///     let league_id = 98765;
     ///
-    ///     match fpl.get_h2h_league(league_id).await {
-    ///         Ok(league) => {
-    ///             // Process the head to head league standings
-    ///             println!("{:?}", league);
+    ///     match fpl.refresh_fixtures().await {
+    ///         Ok(fixtures) => {
+    ///             // Process the freshly fetched fixtures
+    ///             println!("{:?}", fixtures);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -640,7 +1260,6 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `league_id` should be a valid identifier of an existing FPL head to head league.
     ///
     /// # Panics
     ///
@@ -649,38 +1268,57 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    /// Use caution when relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_h2h_league(&self, league_id: i64) -> Result<H2HLeague, FplError> {
-        let url = format!(
-            "https://fantasy.premierleague.com/api/leagues-h2h-matches/league/{}/",
-            league_id
-        );
-        return self.fetch(url).await;
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.refresh_fixtures", skip_all)
+    )]
+    pub async fn refresh_fixtures(&self) -> Result<Fixtures, FplError> {
+        self.fixtures
+            .fetch(|validators| self.fetch_fixtures(validators))
+            .await
     }
 
-    /// Asynchronously retrieves the picks made by a Fantasy Premier League user for a specific gameweek.
-    ///
-    /// # Arguments
+    /// Asynchronously fetches the fixtures, unless they have not changed since `validators` were sent.
+    async fn fetch_fixtures(
+        &self,
+        validators: Option<Validators>,
+    ) -> Result<Conditional<Fixtures>, FplError> {
+        let url = format!("{}fixtures/", self.base_url);
+        let fixtures = self
+            .fetch_conditional(url, &RequestOptions::default(), validators.as_ref())
+            .await?;
+        #[cfg(not(target_arch = "wasm32"))]
+        if let (Some(disk_cache), Conditional::Modified(fixtures, _)) =
+            (&self.disk_cache, &fixtures)
+        {
+            disk_cache.save(FIXTURES_FILE, fixtures).await;
+        }
+        Ok(fixtures)
+    }
+
+    /// Asynchronously retrieves the Fantasy Premier League fixtures that have not been played yet.
     ///
-    /// * `user_id` - An `i64` representing the unique identifier of the FPL user.
-    /// * `gameweek_id` - An `i64` representing the unique identifier of the FPL gameweek.
+    /// Only the upcoming fixtures are requested, which makes for a much smaller response than
+    /// [`get_fixtures`](struct.Fpl.html#method.get_fixtures).
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with the user's picks for the specified gameweek on success,
-    /// or an `FplError` on failure.
+    /// Returns a `Result` with the upcoming fixtures on success, or an `FplError` on failure.
+    ///
+    /// Fixtures that have not been scheduled yet, such as postponed ones, have their `event` set to `None`.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `UserPicks` type.
+    /// - If there is an error deserializing the JSON response into the `Fixtures` type.
     ///
     /// # Examples
     ///
@@ -690,13 +1328,11 @@ impl Fpl {
     /// #[tokio::main]
     /// async fn main() {
     ///     let fpl = Fpl::new();
-    ///     let user_id = 12345;
-    ///     let gameweek_id = 5;
     ///
-    ///     match fpl.get_user_picks(user_id, gameweek_id).await {
-    ///         Ok(user_picks) => {
-    ///             // Process the user's picks for the specified gameweek
-    ///             println!("{:?}", user_picks);
+    ///     match fpl.get_upcoming_fixtures().await {
+    ///         Ok(fixtures) => {
+    ///             // Process the upcoming fixtures
+    ///             println!("{:?}", fixtures);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -709,7 +1345,6 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `user_id` and `gameweek_id` should be valid identifiers.
     ///
     /// # Panics
     ///
@@ -718,42 +1353,35 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted user or gameweek IDs or relying on FPL API data.
+    /// Use caution when relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_user_picks(
-        &self,
-        user_id: i64,
-        gameweek_id: i64,
-    ) -> Result<UserPicks, FplError> {
-        let url = format!(
-            "https://fantasy.premierleague.com/api/entry/{}/event/{}/picks/",
-            user_id, gameweek_id
-        );
-        return self.fetch(url).await;
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_upcoming_fixtures", skip_all)
+    )]
+    pub async fn get_upcoming_fixtures(&self) -> Result<Fixtures, FplError> {
+        let url = format!("{}fixtures/?future=1", self.base_url);
+        self.fetch(url).await
     }
 
-    /// Asynchronously retrieves information about a Fantasy Premier League team.
-    ///
-    /// # Arguments
+    /// Asynchronously retrieves the Fantasy Premier League fixtures that have been played.
     ///
-    /// * `team_id` - An `i64` representing the unique identifier of the FPL team.
+    /// All fixtures are requested and the finished ones are kept.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with team information on success, or an `FplError` on failure.
-    ///
-    /// If the team with the specified ID is not found, it returns `Ok(None)`.
+    /// Returns a `Result` with the finished fixtures on success, or an `FplError` on failure.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Team` type.
+    /// - If there is an error deserializing the JSON response into the `Fixtures` type.
     ///
     /// # Examples
     ///
@@ -762,17 +1390,12 @@ impl Fpl {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut fpl = Fpl::new();
-    ///     let team_id = 6789;
+    ///     let fpl = Fpl::new();
     ///
-    ///     match fpl.get_team(team_id).await {
-    ///         Ok(Some(team)) => {
-    ///             // Process the team information
-    ///             println!("{:?}", team);
-    ///         }
-    ///         Ok(None) => {
-    ///             // Handle the case when the team is not found
-    ///             println!("Team not found");
+    ///     match fpl.get_finished_fixtures().await {
+    ///         Ok(fixtures) => {
+    ///             // Process the finished fixtures
+    ///             println!("{:?}", fixtures);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -785,7 +1408,6 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `team_id` should be a valid identifier of an existing FPL team.
     ///
     /// # Panics
     ///
@@ -794,49 +1416,44 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted team IDs or relying on FPL API data.
+    /// Use caution when relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_team(&mut self, team_id: i64) -> Result<Option<Team>, FplError> {
-        let bootstrap_static = match &self.bootstrap_static {
-            Some(bootstrap_static) => bootstrap_static.clone(),
-            None => match self.get_bootstrap_static().await {
-                Ok(bootstrap_static) => bootstrap_static,
-                Err(e) => return Err(e),
-            },
-        };
-        Ok(bootstrap_static
-            .clone()
-            .teams
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_finished_fixtures", skip_all)
+    )]
+    pub async fn get_finished_fixtures(&self) -> Result<Fixtures, FplError> {
+        let all_fixtures = self.get_fixtures().await?;
+        Ok(all_fixtures
             .into_iter()
-            .filter(|team| team_id == team.id)
-            .collect::<Vec<Team>>()
-            .first()
-            .cloned())
+            .filter(|fixture| fixture.finished)
+            .collect())
     }
 
-    /// Asynchronously retrieves information about multiple Fantasy Premier League teams.
+    /// Asynchronously retrieves the Fantasy Premier League fixtures of a team.
+    ///
+    /// All fixtures are requested and the ones the team plays in, home or away, are kept.
     ///
     /// # Arguments
     ///
-    /// * `team_ids` - A `Vec<i64>` containing unique identifiers of the FPL teams.
+    /// * `team_id` - An `i64` representing the unique identifier of the team.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with team information for the specified team IDs on success,
-    /// or an `FplError` on failure.
+    /// Returns a `Result` with the fixtures of the team, sorted by gameweek, on success, or an `FplError` on failure.
     ///
-    /// If the provided `team_ids` is empty, it returns information about all FPL teams.
+    /// Postponed fixtures, which do not have a gameweek yet, are placed at the end.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Team` type.
+    /// - If there is an error deserializing the JSON response into the `Fixtures` type.
     ///
     /// # Examples
     ///
@@ -845,13 +1462,13 @@ impl Fpl {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut fpl = Fpl::new();
-    ///     let team_ids = vec![123, 456, 789];
+    ///     let fpl = Fpl::new();
+    ///     let team_id = 1;
     ///
-    ///     match fpl.get_teams(team_ids).await {
-    ///         Ok(teams) => {
-    ///             // Process the team information
-    ///             println!("{:?}", teams);
+    ///     match fpl.get_fixtures_for_team(team_id).await {
+    ///         Ok(fixtures) => {
+    ///             // Process the fixtures of the team
+    ///             println!("{:?}", fixtures);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -864,7 +1481,7 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `team_ids` should be valid identifiers of existing FPL teams.
+    /// The provided `team_id` should be a valid identifier of an existing team.
     ///
     /// # Panics
     ///
@@ -873,44 +1490,44 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted team IDs or relying on FPL API data.
+    /// Use caution when relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_teams(&mut self, team_ids: Vec<i64>) -> Result<Vec<Team>, FplError> {
-        let bootstrap_static = match &self.bootstrap_static {
-            Some(bootstrap_static) => bootstrap_static.clone(),
-            None => match self.get_bootstrap_static().await {
-                Ok(bootstrap_static) => bootstrap_static,
-                Err(e) => return Err(e),
-            },
-        };
-        match team_ids {
-            x if x.is_empty() => Ok(bootstrap_static.teams),
-            t_ids => Ok(bootstrap_static
-                .clone()
-                .teams
-                .into_iter()
-                .filter(|team| t_ids.contains(&team.id))
-                .collect()),
-        }
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_fixtures_for_team", skip(self))
+    )]
+    pub async fn get_fixtures_for_team(&self, team_id: i64) -> Result<Fixtures, FplError> {
+        let mut fixtures: Fixtures = self
+            .get_fixtures()
+            .await?
+            .into_iter()
+            .filter(|fixture| fixture.team_h == team_id || fixture.team_a == team_id)
+            .collect();
+        fixtures.sort_by_key(|fixture| (fixture.event.is_none(), fixture.event));
+        Ok(fixtures)
     }
 
-    /// Asynchronously retrieves information about all Fantasy Premier League teams.
+    /// Asynchronously retrieves information about a Fantasy Premier League gameweek fixtures.
+    ///
+    /// # Arguments
+    ///
+    /// * `gameweek_id` - An `i64` representing the a gameweek (from 1 to 38)
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with information about all FPL teams on success,
-    /// or an `FplError` on failure.
+    /// Returns a `Result` with fixture information on success, or an `FplError` on failure.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
+    /// - If `gameweek_id` is not the id of a gameweek of the season, with `FplError::InvalidArgument`.
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Team` type.
+    /// - If there is an error deserializing the JSON response into the `User` type.
     ///
     /// # Examples
     ///
@@ -919,12 +1536,13 @@ impl Fpl {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut fpl = Fpl::new();
+    ///     let fpl = Fpl::new();
+    ///     let gameweek_id = 1;
     ///
-    ///     match fpl.get_all_teams().await {
-    ///         Ok(teams) => {
-    ///             // Process information about all FPL teams
-    ///             println!("{:?}", teams);
+    ///     match fpl.get_gameweek_fixtures(gameweek_id).await {
+    ///         Ok(gameweek_fixtures) => {
+    ///             // Process the gameweek fixtures
+    ///             println!("{:?}", gameweek_fixtures);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -937,6 +1555,8 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `gameweek_id` should be a valid identifier of a gameweek.
+    /// When every fixture of the gameweek is finished in the cached fixtures, they are returned without a request.
     ///
     /// # Panics
     ///
@@ -945,40 +1565,53 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when relying on FPL API data.
+    /// Use caution when passing untrusted user IDs or relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_all_teams(&mut self) -> Result<Vec<Team>, FplError> {
-        match &self.bootstrap_static {
-            Some(bootstrap_static) => Ok(bootstrap_static.clone().teams),
-            None => match self.get_bootstrap_static().await {
-                Ok(bootstrap_static) => Ok(bootstrap_static.teams),
-                Err(e) => return Err(e),
-            },
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_gameweek_fixtures", skip(self))
+    )]
+    pub async fn get_gameweek_fixtures(&self, gameweek_id: i64) -> Result<Fixtures, FplError> {
+        self.check_gameweek_id(gameweek_id)?;
+        let cached = self.fixtures.read(|fixtures, _| {
+            fixtures
+                .iter()
+                .filter(|fixture| fixture.event == Some(gameweek_id))
+                .cloned()
+                .collect::<Fixtures>()
+        });
+        if let Some(gameweek_fixtures) = cached {
+            if !gameweek_fixtures.is_empty()
+                && gameweek_fixtures.iter().all(|fixture| fixture.finished)
+            {
+                return Ok(gameweek_fixtures);
+            }
         }
+        let url = format!("{}fixtures/?event={}", self.base_url, gameweek_id);
+        self.fetch(url).await
     }
 
-    /// Asynchronously retrieves information about a Fantasy Premier League player.
+    /// Asynchronously retrieves information about a Fantasy Premier League fixture.
     ///
     /// # Arguments
     ///
-    /// * `player_id` - An `i64` representing the unique identifier of the FPL player.
+    /// * `fixture_id` - An `i64` representing the unique identifier of the FPL fixture.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with player information on success, or an `FplError` on failure.
-    ///
-    /// If the player with the specified ID is not found, it returns `Ok(None)`.
+    /// Returns a `Result` with fixture information on success, or an `FplError` on failure.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Player` type.
+    /// - If there is an error deserializing the JSON response into the `Fixtures` type.
+    /// - If there is no fixture with the provided `fixture_id`, with `FplError::NotFound`.
     ///
     /// # Examples
     ///
@@ -987,17 +1620,13 @@ impl Fpl {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut fpl = Fpl::new();
-    ///     let player_id = 12345;
+    ///     let fpl = Fpl::new();
+    ///     let fixture_id = 12;
     ///
-    ///     match fpl.get_player(player_id).await {
-    ///         Ok(Some(player)) => {
-    ///             // Process player information
-    ///             println!("{:?}", player);
-    ///         }
-    ///         Ok(None) => {
-    ///             // Handle the case when the player is not found
-    ///             println!("Player not found");
+    ///     match fpl.get_fixture(fixture_id).await {
+    ///         Ok(fixture) => {
+    ///             // Process the fixture information
+    ///             println!("{:?}", fixture);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -1010,7 +1639,9 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `player_id` should be a valid identifier of an existing FPL player.
+    /// The provided `fixture_id` should be a valid identifier of an FPL fixture.
+    /// Fixtures not scheduled in a gameweek yet, such as postponed ones, are returned with `event` set to `None`.
+    /// Finished fixtures are returned from the cached fixtures when they are cached, others are fetched again.
     ///
     /// # Panics
     ///
@@ -1019,47 +1650,129 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted player IDs or relying on FPL API data.
+    /// Use caution when passing untrusted user IDs or relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_player(&mut self, player_id: i64) -> Result<Option<Player>, FplError> {
-        let bootstrap_static = match &self.bootstrap_static {
-            Some(bootstrap_static) => bootstrap_static.clone(),
-            None => match self.get_bootstrap_static().await {
-                Ok(bootstrap_static) => bootstrap_static,
-                Err(e) => return Err(e),
-            },
-        };
-        Ok(bootstrap_static
-            .clone()
-            .elements
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_fixture", skip(self))
+    )]
+    pub async fn get_fixture(&self, fixture_id: i64) -> Result<Fixture, FplError> {
+        let cached = self.fixtures.read(|fixtures, _| {
+            fixtures
+                .iter()
+                .find(|fixture| fixture.id == fixture_id && fixture.finished)
+                .cloned()
+        });
+        if let Some(fixture) = cached.flatten() {
+            return Ok(fixture);
+        }
+        let all_fixtures = self.refresh_fixtures().await?;
+        all_fixtures
+            .into_iter()
+            .find(|fixture| fixture.id == fixture_id)
+            .ok_or(FplError::NotFound)
+    }
+
+    /// Asynchronously retrieves information about a Fantasy Premier League gameweek.
+    ///
+    /// # Arguments
+    ///
+    /// * `gameweek_id` - An `i64` representing the gameweek.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with gameweek information on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Event` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let gameweek_id = 12345;
+    ///
+    ///     match fpl.get_static_gameweek(gameweek_id).await {
+    ///         Ok(Some(gameweek)) => {
+    ///                 // Process the gameweek information
+    ///                 println!("{:?}", gameweek);
+    ///         },
+    ///             Ok(None) => {
+    ///                 eprintln!("Got nothing!");
+    ///             }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         },
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `gameweek_id` should be a valid gameweek.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted user IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_static_gameweek", skip(self))
+    )]
+    pub async fn get_static_gameweek(&self, gameweek_id: i64) -> Result<Option<Event>, FplError> {
+        let all_gameweeks = self.get_static_gameweeks().await?;
+        Ok(all_gameweeks
             .into_iter()
-            .filter(|element| player_id == element.id)
-            .collect::<Players>()
+            .filter(|gameweek| gameweek_id == gameweek.id)
+            .collect::<Vec<Event>>()
             .first()
             .cloned())
     }
 
-    /// Asynchronously retrieves information about multiple Fantasy Premier League players.
+    /// Asynchronously retrieves the Fantasy Premier League player captained by the most FPL managers in a gameweek.
+    ///
+    /// The gameweek only names the player by their id, in its `most_captained` field, which this function resolves
+    /// to the player in the static data.
     ///
     /// # Arguments
     ///
-    /// * `player_ids` - A `Vec<i64>` containing unique identifiers of the FPL players.
+    /// * `gameweek_id` - An `i64` representing the unique identifier of the gameweek.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with player information for the specified player IDs on success,
-    /// or an `FplError` on failure.
+    /// Returns a `Result` with the player on success, or an `FplError` on failure.
+    ///
+    /// If the gameweek is not found, or does not name a player yet, such as before its deadline, it returns `Ok(None)`.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Players` type.
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
     ///
     /// # Examples
     ///
@@ -1068,13 +1781,13 @@ impl Fpl {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut fpl = Fpl::new();
-    ///     let player_ids = vec![12345, 111];
+    ///     let fpl = Fpl::new();
+    ///     let gameweek_id = 5;
     ///
-    ///     match fpl.get_players(player_ids).await {
-    ///         Ok(players) => {
-    ///             // Process players information
-    ///             println!("{:?}", players);
+    ///     match fpl.get_most_captained_player(gameweek_id).await {
+    ///         Ok(player) => {
+    ///             // Process the player
+    ///             println!("{:?}", player);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -1087,7 +1800,7 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
-    /// The provided `player_ids` should be a list of valid identifiers of existing FPL players.
+    /// The gameweeks and players are looked up in the cached static data.
     ///
     /// # Panics
     ///
@@ -1096,42 +1809,45 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when passing untrusted player IDs or relying on FPL API data.
+    /// Use caution when passing untrusted gameweek IDs or relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_players(&mut self, player_ids: Vec<i64>) -> Result<Players, FplError> {
-        let bootstrap_static = match &self.bootstrap_static {
-            Some(bootstrap_static) => bootstrap_static.clone(),
-            None => match self.get_bootstrap_static().await {
-                Ok(bootstrap_static) => bootstrap_static,
-                Err(e) => return Err(e),
-            },
-        };
-
-        Ok(bootstrap_static
-            .clone()
-            .elements
-            .into_iter()
-            .filter(|element| player_ids.contains(&element.id))
-            .collect::<Players>())
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_most_captained_player", skip(self))
+    )]
+    pub async fn get_most_captained_player(
+        &self,
+        gameweek_id: i64,
+    ) -> Result<Option<Player>, FplError> {
+        self.gameweek_player(gameweek_id, |gameweek| gameweek.most_captained)
+            .await
     }
 
-    /// Asynchronously retrieves information about all Fantasy Premier League players.
+    /// Asynchronously retrieves the Fantasy Premier League player selected by the most FPL managers in a gameweek.
+    ///
+    /// The gameweek only names the player by their id, in its `most_selected` field, which this function resolves
+    /// to the player in the static data.
+    ///
+    /// # Arguments
+    ///
+    /// * `gameweek_id` - An `i64` representing the unique identifier of the gameweek.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with information about all FPL players on success,
-    /// or an `FplError` on failure.
+    /// Returns a `Result` with the player on success, or an `FplError` on failure.
+    ///
+    /// If the gameweek is not found, or does not name a player yet, such as before its deadline, it returns `Ok(None)`.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Players` type.
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
     ///
     /// # Examples
     ///
@@ -1140,12 +1856,13 @@ impl Fpl {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut fpl = Fpl::new();
+    ///     let fpl = Fpl::new();
+    ///     let gameweek_id = 5;
     ///
-    ///     match fpl.get_all_players().await {
-    ///         Ok(players) => {
-    ///             // Process information about all FPL players
-    ///             println!("{:?}", players);
+    ///     match fpl.get_most_selected_player(gameweek_id).await {
+    ///         Ok(player) => {
+    ///             // Process the player
+    ///             println!("{:?}", player);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -1158,6 +1875,7 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The gameweeks and players are looked up in the cached static data.
     ///
     /// # Panics
     ///
@@ -1166,35 +1884,45 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when relying on FPL API data.
+    /// Use caution when passing untrusted gameweek IDs or relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_all_players(&mut self) -> Result<Players, FplError> {
-        match &self.bootstrap_static {
-            Some(bootstrap_static) => Ok(bootstrap_static.clone().elements),
-            None => match self.get_bootstrap_static().await {
-                Ok(bootstrap_static) => Ok(bootstrap_static.elements),
-                Err(e) => return Err(e),
-            },
-        }
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_most_selected_player", skip(self))
+    )]
+    pub async fn get_most_selected_player(
+        &self,
+        gameweek_id: i64,
+    ) -> Result<Option<Player>, FplError> {
+        self.gameweek_player(gameweek_id, |gameweek| gameweek.most_selected)
+            .await
     }
 
-    /// Asynchronously retrieves information about static gameweeks in the Fantasy Premier League.
+    /// Asynchronously retrieves the Fantasy Premier League player transferred in by the most FPL managers in a gameweek.
+    ///
+    /// The gameweek only names the player by their id, in its `most_transferred_in` field, which this function resolves
+    /// to the player in the static data.
+    ///
+    /// # Arguments
+    ///
+    /// * `gameweek_id` - An `i64` representing the unique identifier of the gameweek.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with information about static gameweeks on success,
-    /// or an `FplError` on failure.
+    /// Returns a `Result` with the player on success, or an `FplError` on failure.
+    ///
+    /// If the gameweek is not found, or does not name a player yet, such as before its deadline, it returns `Ok(None)`.
     ///
     /// # Errors
     ///
     /// This function may return an `FplError` in the following cases:
     /// - If there is a failure when making the request to the FPL API.
     /// - If the HTTP response status code is not OK (200).
-    /// - If there is an error deserializing the JSON response into the `Vec<Event>` type.
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
     ///
     /// # Examples
     ///
@@ -1203,12 +1931,13 @@ impl Fpl {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut fpl = Fpl::new();
+    ///     let fpl = Fpl::new();
+    ///     let gameweek_id = 5;
     ///
-    ///     match fpl.get_static_gameweeks().await {
-    ///         Ok(gameweeks) => {
-    ///             // Process information about static gameweeks
-    ///             println!("{:?}", gameweeks);
+    ///     match fpl.get_most_transferred_in_player(gameweek_id).await {
+    ///         Ok(player) => {
+    ///             // Process the player
+    ///             println!("{:?}", player);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -1221,6 +1950,7 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The gameweeks and players are looked up in the cached static data.
     ///
     /// # Panics
     ///
@@ -1229,30 +1959,38 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when relying on FPL API data.
+    /// Use caution when passing untrusted gameweek IDs or relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_static_gameweeks(&mut self) -> Result<Vec<Event>, FplError> {
-        match &self.bootstrap_static {
-            Some(bootstrap_static) => Ok(bootstrap_static.clone().events),
-            None => match self.get_bootstrap_static().await {
-                Ok(bootstrap_static) => Ok(bootstrap_static.events),
-                Err(e) => return Err(e),
-            },
-        }
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_most_transferred_in_player", skip(self))
+    )]
+    pub async fn get_most_transferred_in_player(
+        &self,
+        gameweek_id: i64,
+    ) -> Result<Option<Player>, FplError> {
+        self.gameweek_player(gameweek_id, |gameweek| gameweek.most_transferred_in)
+            .await
     }
 
-    /// Asynchronously retrieves static data from the Fantasy Premier League API.
+    /// Asynchronously retrieves the Fantasy Premier League player who scored the most points in a gameweek.
     ///
-    /// This function is typically used to fetch data that does not change frequently,
-    /// such as player details, team information, and gameweek details.
+    /// The gameweek only names the player by their id, in its `top_element` field, which this function resolves
+    /// to the player in the static data.
+    ///
+    /// # Arguments
+    ///
+    /// * `gameweek_id` - An `i64` representing the unique identifier of the gameweek.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with static data on success, or an `FplError` on failure.
+    /// Returns a `Result` with the player on success, or an `FplError` on failure.
+    ///
+    /// If the gameweek is not found, or does not name a player yet, such as before its deadline, it returns `Ok(None)`.
     ///
     /// # Errors
     ///
@@ -1268,12 +2006,13 @@ impl Fpl {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut fpl = Fpl::new();
+    ///     let fpl = Fpl::new();
+    ///     let gameweek_id = 5;
     ///
-    ///     match fpl.get_bootstrap_static().await {
-    ///         Ok(bootstrap_static) => {
-    ///             // Process static data from the FPL API
-    ///             println!("{:?}", bootstrap_static);
+    ///     match fpl.get_top_player(gameweek_id).await {
+    ///         Ok(player) => {
+    ///             // Process the player
+    ///             println!("{:?}", player);
     ///         }
     ///         Err(err) => {
     ///             // Handle the error
@@ -1286,6 +2025,7 @@ impl Fpl {
     /// # Note
     ///
     /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The gameweeks and players are looked up in the cached static data.
     ///
     /// # Panics
     ///
@@ -1294,118 +2034,7002 @@ impl Fpl {
     /// # Safety
     ///
     /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
-    /// Use caution when relying on FPL API data.
+    /// Use caution when passing untrusted gameweek IDs or relying on FPL API data.
     ///
     /// # See Also
     ///
     /// - [`fetch`](struct.Fpl.html#method.fetch)
     /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
-    pub async fn get_bootstrap_static(&mut self) -> Result<BootstrapStatic, FplError> {
-        match &self.bootstrap_static {
-            Some(b) => return Ok(b.clone()),
-            None => {}
-        }
-        let url = String::from("https://fantasy.premierleague.com/api/bootstrap-static/");
-        let bootstrap_static: BootstrapStatic = self.fetch(url).await?;
-        self.bootstrap_static = Some(bootstrap_static.clone());
-        return Ok(bootstrap_static);
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test]
-    async fn test_get_user() {
-        let fpl = Fpl::new();
-        let user_id = 5489342;
-        match fpl.get_user(user_id).await {
-            Ok(user) => assert_eq!(user.id, user_id),
-            Err(e) => panic!("Got this guy: {}", e),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_get_bootstrap_static() {
-        let mut fpl = Fpl::new();
-        let bootstrap_static = fpl.get_bootstrap_static().await.unwrap();
-        assert!(bootstrap_static.teams.len() > 0);
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_top_player", skip(self))
+    )]
+    pub async fn get_top_player(&self, gameweek_id: i64) -> Result<Option<Player>, FplError> {
+        self.gameweek_player(gameweek_id, |gameweek| gameweek.top_element)
+            .await
     }
 
-    #[tokio::test]
-    async fn test_get_all_teams() {
-        let mut fpl = Fpl::new();
-        let teams = fpl.get_all_teams().await.unwrap();
-        assert!(teams.len() == 20);
+    /// Asynchronously retrieves static information about the current gameweek of the Fantasy Premier League season.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the current gameweek on success, or an `FplError` on failure.
+    ///
+    /// There is no current gameweek before the first deadline of the season. In that case, it returns `Ok` with `None`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_current_gameweek().await {
+    ///         Ok(gameweek) => {
+    ///             // Process the current gameweek
+    ///             println!("{:?}", gameweek);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The gameweeks are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_current_gameweek", skip_all)
+    )]
+    pub async fn get_current_gameweek(&self) -> Result<Option<Event>, FplError> {
+        let all_gameweeks = self.get_static_gameweeks().await?;
+        Ok(all_gameweeks
+            .into_iter()
+            .find(|gameweek| gameweek.is_current))
     }
 
-    #[tokio::test]
-    async fn test_get_teams() {
-        let mut fpl = Fpl::new();
-        let teams = fpl.get_teams(vec![1, 2]).await.unwrap();
-        assert!(teams.len() == 2);
-    }
+    /// Asynchronously retrieves static information about the next gameweek of the Fantasy Premier League season.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the next gameweek on success, or an `FplError` on failure.
+    ///
+    /// There is no next gameweek after the last deadline of the season. In that case, it returns `Ok` with `None`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_next_gameweek().await {
+    ///         Ok(gameweek) => {
+    ///             // Process the next gameweek
+    ///             println!("{:?}", gameweek);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The gameweeks are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_next_gameweek", skip_all)
+    )]
+    pub async fn get_next_gameweek(&self) -> Result<Option<Event>, FplError> {
+        let all_gameweeks = self.get_static_gameweeks().await?;
+        Ok(all_gameweeks.into_iter().find(|gameweek| gameweek.is_next))
+    }
+
+    /// Asynchronously retrieves static information about the previous gameweek of the Fantasy Premier League season.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the previous gameweek on success, or an `FplError` on failure.
+    ///
+    /// There is no previous gameweek before the second deadline of the season. In that case, it returns `Ok` with `None`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_previous_gameweek().await {
+    ///         Ok(gameweek) => {
+    ///             // Process the previous gameweek
+    ///             println!("{:?}", gameweek);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The gameweeks are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_previous_gameweek", skip_all)
+    )]
+    pub async fn get_previous_gameweek(&self) -> Result<Option<Event>, FplError> {
+        let all_gameweeks = self.get_static_gameweeks().await?;
+        Ok(all_gameweeks
+            .into_iter()
+            .find(|gameweek| gameweek.is_previous))
+    }
+
+    /// Asynchronously retrieves live data for a specific Fantasy Premier League gameweek.
+    ///
+    /// # Arguments
+    ///
+    /// * `gameweek_id` - An `i64` representing the unique identifier of the FPL gameweek.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with live gameweek data on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `gameweek_id` is not the id of a gameweek of the season, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Gameweek` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let gameweek_id = 5;
+    ///
+    ///     match fpl.get_live_gameweek(gameweek_id).await {
+    ///         Ok(gameweek) => {
+    ///             // Process the live gameweek data
+    ///             println!("{:?}", gameweek);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `gameweek_id` should be a valid identifier of an existing FPL gameweek.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted gameweek IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_live_gameweek", skip(self))
+    )]
+    pub async fn get_live_gameweek(&self, gameweek_id: i64) -> Result<Gameweek, FplError> {
+        self.check_gameweek_id(gameweek_id)?;
+        self.live_gameweeks
+            .run(gameweek_id, || {
+                self.fetch(format!("{}event/{}/live", self.base_url, gameweek_id))
+            })
+            .await
+    }
+
+    /// Asynchronously retrieves live data for a specific Fantasy Premier League gameweek, with options overriding
+    /// the configuration of this instance for this request.
+    ///
+    /// This is useful when polling the live data, to give up on a slow request sooner than other requests would.
+    ///
+    /// # Arguments
+    ///
+    /// * `gameweek_id` - An `i64` representing the unique identifier of the FPL gameweek.
+    /// * `options` - A reference to the `RequestOptions` of this request.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with live gameweek data on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `gameweek_id` is not the id of a gameweek of the season, with `FplError::InvalidArgument`.
+    /// - If the request takes longer than the timeout of the options, with `FplError::Timeout`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Gameweek` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// use std::time::Duration;
+    ///
+    /// use fpl_rs::request_options::RequestOptions;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let gameweek_id = 5;
+    ///     let options = RequestOptions::new().timeout(Duration::from_secs(5));
+    ///
+    ///     match fpl.get_live_gameweek_with_options(gameweek_id, &options).await {
+    ///         Ok(gameweek) => {
+    ///             // Process the live gameweek data
+    ///             println!("{:?}", gameweek);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch_with_options` method internally to make a request to the FPL API.
+    /// The provided `gameweek_id` should be a valid identifier of an existing FPL gameweek.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_live_gameweek_with_options", skip(self, options))
+    )]
+    pub async fn get_live_gameweek_with_options(
+        &self,
+        gameweek_id: i64,
+        options: &RequestOptions,
+    ) -> Result<Gameweek, FplError> {
+        self.check_gameweek_id(gameweek_id)?;
+        let url = format!("{}event/{}/live", self.base_url, gameweek_id);
+        self.fetch_with_options(url, options).await
+    }
+
+    /// Asynchronously retrieves the processing status of the current Fantasy Premier League gameweek.
+    ///
+    /// The status tells whether bonus points have been added for each day of the gameweek
+    /// and whether the league tables have been updated.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the event status on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `EventStatus` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_event_status().await {
+    ///         Ok(event_status) => {
+    ///             // Process the event status
+    ///             println!("{:?}", event_status);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_event_status", skip_all)
+    )]
+    pub async fn get_event_status(&self) -> Result<EventStatus, FplError> {
+        let url = format!("{}event-status/", self.base_url);
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves the dream team of a Fantasy Premier League gameweek.
+    ///
+    /// # Arguments
+    ///
+    /// * `gameweek_id` - An `i64` representing the a gameweek (from 1 to 38)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the dream team on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `gameweek_id` is not the id of a gameweek of the season, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `DreamTeam` type.
+    /// - If the gameweek has not started yet, so there is no dream team to return, with `FplError::NotYetAvailable`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let gameweek_id = 1;
+    ///
+    ///     match fpl.get_dream_team(gameweek_id).await {
+    ///         Ok(dream_team) => {
+    ///             // Process the dream team
+    ///             println!("{:?}", dream_team);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `gameweek_id` is validated before any request is made.
+    /// The FPL API only returns a dream team once the gameweek has started.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted gameweek IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_dream_team", skip(self))
+    )]
+    pub async fn get_dream_team(&self, gameweek_id: i64) -> Result<DreamTeam, FplError> {
+        self.check_gameweek_id(gameweek_id)?;
+        let url = format!("{}dream-team/{}/", self.base_url, gameweek_id);
+        let dream_team: DreamTeam = self.fetch(url).await?;
+        if dream_team.team.is_empty() {
+            return Err(FplError::NotYetAvailable {
+                gameweek: gameweek_id,
+            });
+        }
+        Ok(dream_team)
+    }
+
+    /// Asynchronously retrieves the players of the dream team of a Fantasy Premier League gameweek.
+    ///
+    /// Each dream team pick is resolved to its full `Player` using the cached static data.
+    ///
+    /// # Arguments
+    ///
+    /// * `gameweek_id` - An `i64` representing the a gameweek (from 1 to 38)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the dream team players, in dream team order and paired with
+    /// the points they scored in the gameweek, on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If the provided `gameweek_id` is not between 1 and 38.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `DreamTeam` type.
+    /// - If the gameweek has not started yet, so there is no dream team to return, with `FplError::NotYetAvailable`.
+    /// - If a dream team pick is of a player which is not in the static data, with `FplError::UnknownPlayers` listing every such id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let gameweek_id = 1;
+    ///
+    ///     match fpl.get_dream_team_players(gameweek_id).await {
+    ///         Ok(dream_team_players) => {
+    ///             // Process the dream team players
+    ///             println!("{:?}", dream_team_players);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `gameweek_id` is validated before any request is made.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted gameweek IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_dream_team_players", skip(self))
+    )]
+    pub async fn get_dream_team_players(
+        &self,
+        gameweek_id: i64,
+    ) -> Result<Vec<(Player, i64)>, FplError> {
+        let dream_team = self.get_dream_team(gameweek_id).await?;
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        let mut players = Vec::with_capacity(dream_team.team.len());
+        let mut unknown = Vec::new();
+        for pick in dream_team.team {
+            match bootstrap_static.player(pick.element) {
+                Some(player) => players.push((player.clone(), pick.points)),
+                None => unknown.push(pick.element),
+            }
+        }
+        if !unknown.is_empty() {
+            return Err(FplError::UnknownPlayers { ids: unknown });
+        }
+        Ok(players)
+    }
+
+    /// Asynchronously retrieves every transfer made by a Fantasy Premier League user this season.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - An `i64` representing the unique identifier of the FPL user.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the user's transfers on success, or an `FplError` on failure.
+    ///
+    /// If the user has not made any transfers, it returns `Ok` with an empty `Vec`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `user_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Transfers` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let user_id = 12345;
+    ///
+    ///     match fpl.get_entry_transfers(user_id).await {
+    ///         Ok(transfers) => {
+    ///             // Process the user's transfers
+    ///             println!("{:?}", transfers);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `user_id` should be a valid identifier of an existing FPL user.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted user IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_entry_transfers", skip(self))
+    )]
+    pub async fn get_entry_transfers(&self, user_id: i64) -> Result<Transfers, FplError> {
+        Self::check_id("user_id", user_id)?;
+        let url = format!("{}entry/{}/transfers/", self.base_url, user_id);
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves every transfer made by a Fantasy Premier League user this season.
+    ///
+    /// This is an alias of [`get_entry_transfers`](struct.Fpl.html#method.get_entry_transfers).
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_transfers", skip(self))
+    )]
+    pub async fn get_transfers(&self, user_id: i64) -> Result<Transfers, FplError> {
+        self.get_entry_transfers(user_id).await
+    }
+
+    /// Asynchronously retrieves the transfers made by a Fantasy Premier League user for the upcoming deadline.
+    ///
+    /// Unlike [`get_entry_transfers`](struct.Fpl.html#method.get_entry_transfers), which returns the transfers of
+    /// the whole season, only the transfers that have not been processed yet are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - An `i64` representing the unique identifier of the FPL user.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the user's latest transfers on success, or an `FplError` on failure.
+    ///
+    /// If the user has not made any transfers for the upcoming deadline, it returns `Ok` with an empty `Vec`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `user_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200) or Not Found (404).
+    /// - If there is an error deserializing the JSON response into the `Transfers` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let user_id = 12345;
+    ///
+    ///     match fpl.get_user_latest_transfers(user_id).await {
+    ///         Ok(transfers) => {
+    ///             // Process the user's latest transfers
+    ///             println!("{:?}", transfers);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `user_id` should be a valid identifier of an existing FPL user.
+    /// In some seasons the API answers with Not Found (404) for users without any transfers, which is treated as no transfers.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted user IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_user_latest_transfers", skip(self))
+    )]
+    pub async fn get_user_latest_transfers(&self, user_id: i64) -> Result<Transfers, FplError> {
+        Self::check_id("user_id", user_id)?;
+        let url = format!("{}entry/{}/transfers-latest/", self.base_url, user_id);
+        match self.fetch(url).await {
+            Err(err) if err.is_not_found() => Ok(vec![]),
+            result => result,
+        }
+    }
+
+    /// Asynchronously retrieves standings data for a Fantasy Premier League classic league.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL classic league.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with classic league standings on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `league_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `ClassicLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///
+    ///     match fpl.get_classic_league(league_id).await {
+    ///         Ok(league) => {
+    ///             // Process the classic league standings
+    ///             println!("{:?}", league);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `league_id` should be a valid identifier of an existing FPL classic league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_classic_league", skip(self))
+    )]
+    pub async fn get_classic_league(&self, league_id: i64) -> Result<ClassicLeague, FplError> {
+        Self::check_id("league_id", league_id)?;
+        let url = format!("{}leagues-classic/{}/standings/", self.base_url, league_id);
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves a page of standings data for a Fantasy Premier League classic league.
+    ///
+    /// The standings are split in pages of 50 entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL classic league.
+    /// * `page` - An `i64` representing the page of standings to retrieve, starting from 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the page of classic league standings on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `league_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `ClassicLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///     let page = 2;
+    ///
+    ///     match fpl.get_classic_league_page(league_id, page).await {
+    ///         Ok(league) => {
+    ///             // Process the page of classic league standings
+    ///             println!("{:?}", league);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `league_id` should be a valid identifier of an existing FPL classic league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_classic_league_page", skip(self))
+    )]
+    pub async fn get_classic_league_page(
+        &self,
+        league_id: i64,
+        page: i64,
+    ) -> Result<ClassicLeague, FplError> {
+        Self::check_id("league_id", league_id)?;
+        let url = format!(
+            "{}leagues-classic/{}/standings/?page_standings={}",
+            self.base_url, league_id, page
+        );
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves a Fantasy Premier League classic league with a given page of standings and of new entries.
+    ///
+    /// The two pages are independent: the standings and the new entries are paged through separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL classic league.
+    /// * `page_standings` - An `i64` representing the page of standings to retrieve, starting at 1.
+    /// * `page_new_entries` - An `i64` representing the page of new entries to retrieve, starting at 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the classic league on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `league_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `ClassicLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///
+    ///     match fpl.get_classic_league_with_pages(league_id, 2, 1).await {
+    ///         Ok(league) => {
+    ///             // Process the pages of standings and new entries
+    ///             println!("{:?}", league);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `league_id` should be a valid identifier of an existing FPL classic league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_classic_league_with_pages", skip(self))
+    )]
+    pub async fn get_classic_league_with_pages(
+        &self,
+        league_id: i64,
+        page_standings: i64,
+        page_new_entries: i64,
+    ) -> Result<ClassicLeague, FplError> {
+        Self::check_id("league_id", league_id)?;
+        let url = format!(
+            "{}leagues-classic/{}/standings/?page_standings={}&page_new_entries={}",
+            self.base_url, league_id, page_standings, page_new_entries
+        );
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves the complete standings of a Fantasy Premier League classic league.
+    ///
+    /// Every page of standings is requested, one after another, and the results are merged.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL classic league.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the classic league and all its standings on success, or an `FplError` on failure.
+    ///
+    /// The returned standings contain the entries of every page and have `has_next` set to `false`,
+    /// unless the league has more than [`MAX_PAGES`](constant.MAX_PAGES.html) pages, in which case only
+    /// the entries of the first pages are returned and `has_next` is left to `true`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `ClassicLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///
+    ///     match fpl.get_full_classic_league(league_id).await {
+    ///         Ok(league) => {
+    ///             // Process the complete classic league standings
+    ///             println!("{:?}", league);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// One request is made per page of 50 entries, so this can take a while for large leagues.
+    /// The provided `league_id` should be a valid identifier of an existing FPL classic league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_full_classic_league", skip(self))
+    )]
+    pub async fn get_full_classic_league(&self, league_id: i64) -> Result<ClassicLeague, FplError> {
+        let mut classic_league = self.get_classic_league_page(league_id, 1).await?;
+        let mut pages = 1;
+        while classic_league.standings.has_next && pages < MAX_PAGES {
+            pages += 1;
+            let next_page = classic_league.standings.page + 1;
+            let next = self.get_classic_league_page(league_id, next_page).await?;
+            classic_league
+                .standings
+                .results
+                .extend(next.standings.results);
+            classic_league.standings.has_next = next.standings.has_next;
+            classic_league.standings.page = next.standings.page;
+        }
+        Ok(classic_league)
+    }
+
+    /// Asynchronously retrieves the complete standings of a Fantasy Premier League classic league.
+    ///
+    /// This is an alias of [`get_full_classic_league`](struct.Fpl.html#method.get_full_classic_league).
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_classic_league_all_pages", skip(self))
+    )]
+    pub async fn get_classic_league_all_pages(
+        &self,
+        league_id: i64,
+    ) -> Result<ClassicLeague, FplError> {
+        self.get_full_classic_league(league_id).await
+    }
+
+    /// Streams the standings entries of a Fantasy Premier League classic league, requesting its
+    /// pages lazily, one at a time.
+    ///
+    /// Unlike [`get_full_classic_league`](struct.Fpl.html#method.get_full_classic_league), which holds
+    /// every entry in memory, this only holds the entries of the page being consumed, so it suits very
+    /// large leagues, such as the overall league, whose entries are processed one at a time, such as to
+    /// write them to a database. There is no limit on the number of pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL classic league.
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of the standings entries, in rank order, on success, or of an `FplError` on failure.
+    ///
+    /// The stream ends after the entries of the last page, or after the first error.
+    ///
+    /// # Errors
+    ///
+    /// The stream may yield an `FplError` in the following cases:
+    /// - If `league_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `ClassicLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///
+    ///     let mut standings = Box::pin(fpl.classic_league_standings_stream(league_id));
+    ///     while let Some(entry) = standings.next().await {
+    ///         match entry {
+    ///             Ok(entry) => {
+    ///                 // Process the standings entry
+    ///                 println!("{:?}", entry);
+    ///             }
+    ///             Err(err) => {
+    ///                 // Handle the error
+    ///                 eprintln!("Error: {}", err);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// Nothing is requested until the stream is polled, and the next page of 50 entries is only requested
+    /// once every entry of the previous page has been consumed, so a slow consumer slows the requests down
+    /// rather than entries piling up in memory. Use [`buffered`](https://docs.rs/futures/latest/futures/stream/trait.StreamExt.html#method.buffered)
+    /// or a channel to request pages ahead of the consumer instead.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    pub fn classic_league_standings_stream(
+        &self,
+        league_id: i64,
+    ) -> impl Stream<Item = Result<models::classic_league::Result, FplError>> + '_ {
+        stream::unfold(Some(1), move |page| async move {
+            let page = page?;
+            match self.get_classic_league_page(league_id, page).await {
+                Ok(classic_league) => {
+                    let standings = classic_league.standings;
+                    let next_page = standings.has_next.then_some(standings.page + 1);
+                    let entries = stream::iter(standings.results.into_iter().map(Ok));
+                    Some((entries.left_stream(), next_page))
+                }
+                Err(err) => Some((stream::iter([Err(err)]).right_stream(), None)),
+            }
+        })
+        .flatten()
+    }
+
+    /// Asynchronously retrieves a page of the overall Fantasy Premier League standings.
+    ///
+    /// Every entry is part of the overall classic league, see [`OVERALL_LEAGUE_ID`](constant.OVERALL_LEAGUE_ID.html).
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - An `i64` representing the page of standings to retrieve, starting from 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the page of overall standings on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `ClassicLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_overall_league(1).await {
+    ///         Ok(league) => {
+    ///             // Process the top of the overall standings
+    ///             println!("{:?}", league);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_overall_league", skip(self))
+    )]
+    pub async fn get_overall_league(&self, page: i64) -> Result<ClassicLeague, FplError> {
+        self.get_classic_league_page(OVERALL_LEAGUE_ID, page).await
+    }
+
+    /// Asynchronously retrieves the page of the overall standings on which a user is ranked.
+    ///
+    /// The page holds the managers ranked immediately around the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - A reference to the `User` whose overall rank is looked up.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the page of overall standings containing the user on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If the user does not have an overall rank yet, which is the case before the first gameweek has been played,
+    ///   with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `ClassicLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     if let Ok(user) = fpl.get_user(1).await {
+    ///         match fpl.get_overall_rank_page_for_user(&user).await {
+    ///             Ok(league) => {
+    ///                 // Process the managers ranked around the user
+    ///                 println!("{:?}", league);
+    ///             }
+    ///             Err(err) => {
+    ///                 // Handle the error
+    ///                 eprintln!("Error: {}", err);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The page is computed from the `summary_overall_rank` of the user, with 50 entries per page.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_overall_rank_page_for_user", skip_all)
+    )]
+    pub async fn get_overall_rank_page_for_user(
+        &self,
+        user: &User,
+    ) -> Result<ClassicLeague, FplError> {
+        if user.summary_overall_rank <= 0 {
+            return Err(FplError::InvalidArgument {
+                name: "summary_overall_rank",
+                value: user.summary_overall_rank,
+                expected: String::from("a positive rank"),
+            });
+        }
+        let page = (user.summary_overall_rank - 1) / STANDINGS_PAGE_SIZE + 1;
+        self.get_overall_league(page).await
+    }
+
+    /// Asynchronously retrieves a page of the entries that recently joined a Fantasy Premier League classic league.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL classic league.
+    /// * `page` - An `i64` representing the page of new entries to retrieve, starting from 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the page of new entries on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `league_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `ClassicLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///     let page = 1;
+    ///
+    ///     match fpl.get_classic_league_new_entries(league_id, page).await {
+    ///         Ok(new_entries) => {
+    ///             // Process the page of new entries
+    ///             println!("{:?}", new_entries);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `league_id` should be a valid identifier of an existing FPL classic league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_classic_league_new_entries", skip(self))
+    )]
+    pub async fn get_classic_league_new_entries(
+        &self,
+        league_id: i64,
+        page: i64,
+    ) -> Result<NewEntries, FplError> {
+        Self::check_id("league_id", league_id)?;
+        let url = format!(
+            "{}leagues-classic/{}/standings/?page_new_entries={}",
+            self.base_url, league_id, page
+        );
+        let classic_league: ClassicLeague = self.fetch(url).await?;
+        Ok(classic_league.new_entries)
+    }
+
+    /// Asynchronously retrieves every entry that recently joined a Fantasy Premier League classic league.
+    ///
+    /// Every page of new entries is requested, one after another, and the results are merged.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL classic league.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with all the new entries on success, or an `FplError` on failure.
+    ///
+    /// If the new entries have more than [`MAX_PAGES`](constant.MAX_PAGES.html) pages, only the
+    /// new entries of the first pages are returned.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `ClassicLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///
+    ///     match fpl.get_all_classic_league_new_entries(league_id).await {
+    ///         Ok(new_entries) => {
+    ///             // Process every new entry
+    ///             println!("{:?}", new_entries);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// One request is made per page of new entries.
+    /// The provided `league_id` should be a valid identifier of an existing FPL classic league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_all_classic_league_new_entries", skip(self))
+    )]
+    pub async fn get_all_classic_league_new_entries(
+        &self,
+        league_id: i64,
+    ) -> Result<Vec<NewEntry>, FplError> {
+        let mut new_entries = self.get_classic_league_new_entries(league_id, 1).await?;
+        let mut results = new_entries.results;
+        let mut pages = 1;
+        while new_entries.has_next && pages < MAX_PAGES {
+            pages += 1;
+            new_entries = self
+                .get_classic_league_new_entries(league_id, new_entries.page + 1)
+                .await?;
+            results.append(&mut new_entries.results);
+        }
+        Ok(results)
+    }
+
+    /// Asynchronously retrieves the matches of a Fantasy Premier League head to head league.
+    ///
+    /// The league table is not part of this response, use
+    /// [`get_h2h_league_standings`](struct.Fpl.html#method.get_h2h_league_standings) to retrieve it.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL head to head league.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with head to head league matches on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `league_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `H2HLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///
+    ///     match fpl.get_h2h_league(league_id).await {
+    ///         Ok(league) => {
+    ///             // Process the head to head league matches
+    ///             println!("{:?}", league);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `league_id` should be a valid identifier of an existing FPL head to head league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_h2h_league", skip(self))
+    )]
+    pub async fn get_h2h_league(&self, league_id: i64) -> Result<H2HLeague, FplError> {
+        Self::check_id("league_id", league_id)?;
+        let url = format!("{}leagues-h2h-matches/league/{}/", self.base_url, league_id);
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves a page of matches of a Fantasy Premier League head to head league.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL head to head league.
+    /// * `page` - An `i64` representing the page of matches to retrieve, starting from 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the page of head to head league matches on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `league_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `H2HLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///     let page = 2;
+    ///
+    ///     match fpl.get_h2h_league_page(league_id, page).await {
+    ///         Ok(league) => {
+    ///             // Process the page of head to head league matches
+    ///             println!("{:?}", league);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `league_id` should be a valid identifier of an existing FPL head to head league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_h2h_league_page", skip(self))
+    )]
+    pub async fn get_h2h_league_page(
+        &self,
+        league_id: i64,
+        page: i64,
+    ) -> Result<H2HLeague, FplError> {
+        Self::check_id("league_id", league_id)?;
+        let url = format!(
+            "{}leagues-h2h-matches/league/{}/?page={}",
+            self.base_url, league_id, page
+        );
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves every match of a Fantasy Premier League head to head league.
+    ///
+    /// Every page of matches is requested, one after another, and the results are merged.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL head to head league.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with all the head to head league matches on success, or an `FplError` on failure.
+    ///
+    /// The returned league contains the matches of every page and has `has_next` set to `false`,
+    /// unless the league has more than [`MAX_PAGES`](constant.MAX_PAGES.html) pages, in which case only
+    /// the matches of the first pages are returned and `has_next` is left to `true`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `H2HLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///
+    ///     match fpl.get_full_h2h_league(league_id).await {
+    ///         Ok(league) => {
+    ///             // Process every head to head league match
+    ///             println!("{:?}", league);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// One request is made per page of matches, so this can take a while for a whole season.
+    /// The provided `league_id` should be a valid identifier of an existing FPL head to head league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_full_h2h_league", skip(self))
+    )]
+    pub async fn get_full_h2h_league(&self, league_id: i64) -> Result<H2HLeague, FplError> {
+        let mut h2h_league = self.get_h2h_league_page(league_id, 1).await?;
+        let mut pages = 1;
+        while h2h_league.has_next && pages < MAX_PAGES {
+            pages += 1;
+            let next = self
+                .get_h2h_league_page(league_id, h2h_league.page + 1)
+                .await?;
+            h2h_league.results.extend(next.results);
+            h2h_league.has_next = next.has_next;
+            h2h_league.page = next.page;
+        }
+        Ok(h2h_league)
+    }
+
+    /// Asynchronously retrieves every match of a Fantasy Premier League head to head league.
+    ///
+    /// This is an alias of [`get_full_h2h_league`](struct.Fpl.html#method.get_full_h2h_league).
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_h2h_league_all", skip(self))
+    )]
+    pub async fn get_h2h_league_all(&self, league_id: i64) -> Result<H2HLeague, FplError> {
+        self.get_full_h2h_league(league_id).await
+    }
+
+    /// Asynchronously retrieves the matches of a Fantasy Premier League head to head league for a gameweek.
+    ///
+    /// Every page of matches of the gameweek is requested, one after another, and the results are merged.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL head to head league.
+    /// * `gameweek_id` - An `i64` representing the a gameweek (from 1 to 38)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the head to head league matches of the gameweek on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `league_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If `gameweek_id` is not the id of a gameweek of the season, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `H2HLeague` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///     let gameweek_id = 5;
+    ///
+    ///     match fpl.get_h2h_matches_for_gameweek(league_id, gameweek_id).await {
+    ///         Ok(league) => {
+    ///             // Process the head to head league matches of the gameweek
+    ///             println!("{:?}", league);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `league_id` should be a valid identifier of an existing FPL head to head league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_h2h_matches_for_gameweek", skip(self))
+    )]
+    pub async fn get_h2h_matches_for_gameweek(
+        &self,
+        league_id: i64,
+        gameweek_id: i64,
+    ) -> Result<H2HLeague, FplError> {
+        Self::check_id("league_id", league_id)?;
+        self.check_gameweek_id(gameweek_id)?;
+        let mut page = 1;
+        let mut h2h_league = H2HLeague::default();
+        loop {
+            let url = format!(
+                "{}leagues-h2h-matches/league/{}/?event={}&page={}",
+                self.base_url, league_id, gameweek_id, page
+            );
+            let next: H2HLeague = self.fetch(url).await?;
+            h2h_league.results.extend(next.results);
+            h2h_league.has_next = next.has_next;
+            h2h_league.page = next.page;
+            if !h2h_league.has_next || page >= MAX_PAGES {
+                return Ok(h2h_league);
+            }
+            page += 1;
+        }
+    }
+
+    /// Asynchronously retrieves standings data for a Fantasy Premier League head to head league.
+    ///
+    /// The matches played in the league are not part of this response, use
+    /// [`get_h2h_league`](struct.Fpl.html#method.get_h2h_league) to retrieve them.
+    ///
+    /// # Arguments
+    ///
+    /// * `league_id` - An `i64` representing the unique identifier of the FPL head to head league.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with head to head league standings on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `league_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `H2HStandings` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let league_id = 98765;
+    ///
+    ///     match fpl.get_h2h_league_standings(league_id).await {
+    ///         Ok(standings) => {
+    ///             // Process the head to head league standings
+    ///             println!("{:?}", standings);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `league_id` should be a valid identifier of an existing FPL head to head league.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted league IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_h2h_league_standings", skip(self))
+    )]
+    pub async fn get_h2h_league_standings(&self, league_id: i64) -> Result<H2HStandings, FplError> {
+        Self::check_id("league_id", league_id)?;
+        let url = format!("{}leagues-h2h/{}/standings/", self.base_url, league_id);
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves the picks made by a Fantasy Premier League user for a specific gameweek.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - An `i64` representing the unique identifier of the FPL user.
+    /// * `gameweek_id` - An `i64` representing the unique identifier of the FPL gameweek.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the user's picks for the specified gameweek on success,
+    /// or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `user_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If `gameweek_id` is not the id of a gameweek of the season, with `FplError::InvalidArgument`.
+    /// - If the gameweek has not started yet, with `FplError::NotYetAvailable`.
+    /// - If there is no user with the provided `user_id`, with an error for which `is_not_found` returns `true`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `UserPicks` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let user_id = 12345;
+    ///     let gameweek_id = 5;
+    ///
+    ///     match fpl.get_user_picks(user_id, gameweek_id).await {
+    ///         Ok(user_picks) => {
+    ///             // Process the user's picks for the specified gameweek
+    ///             println!("{:?}", user_picks);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `user_id` and `gameweek_id` should be valid identifiers.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted user or gameweek IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_user_picks", skip(self))
+    )]
+    pub async fn get_user_picks(
+        &self,
+        user_id: i64,
+        gameweek_id: i64,
+    ) -> Result<UserPicks, FplError> {
+        Self::check_id("user_id", user_id)?;
+        self.check_gameweek_id(gameweek_id)?;
+        let url = format!(
+            "{}entry/{}/event/{}/picks/",
+            self.base_url, user_id, gameweek_id
+        );
+        match self.fetch(url).await {
+            // The picks of a gameweek which has not started yet do not exist either,
+            // tell it apart from an unknown user with the current gameweek of the user.
+            Err(err) if err.is_not_found() => {
+                let user = self.get_user(user_id).await?;
+                if gameweek_id > user.current_event {
+                    Err(FplError::NotYetAvailable {
+                        gameweek: gameweek_id,
+                    })
+                } else {
+                    Err(err)
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Asynchronously retrieves the picks made by a Fantasy Premier League user for the current gameweek.
+    ///
+    /// The current gameweek of the user is looked up first, then its picks are requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - An `i64` representing the unique identifier of the FPL user.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the user's picks for the current gameweek on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `user_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If the season has not started yet, with `FplError::NotYetAvailable`.
+    /// - If there is no user with the provided `user_id`, with an error for which `is_not_found` returns `true`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `User` or `UserPicks` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let user_id = 12345;
+    ///
+    ///     match fpl.get_latest_user_picks(user_id).await {
+    ///         Ok(user_picks) => {
+    ///             // Process the user's picks for the current gameweek
+    ///             println!("{:?}", user_picks);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `user_id` should be a valid identifier of an existing FPL user.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted user IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_latest_user_picks", skip(self))
+    )]
+    pub async fn get_latest_user_picks(&self, user_id: i64) -> Result<UserPicks, FplError> {
+        let user = self.get_user(user_id).await?;
+        if user.current_event < 1 {
+            return Err(FplError::NotYetAvailable { gameweek: 1 });
+        }
+        self.get_user_picks(user_id, user.current_event).await
+    }
+
+    /// Asynchronously retrieves the current squad of the logged in Fantasy Premier League user.
+    ///
+    /// Unlike the public picks, it contains the selling and purchase prices of the players,
+    /// the chips available and the state of the transfers for the next deadline.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - An `i64` representing the unique identifier of the logged in FPL user.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the user's squad on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `user_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If no successful login has been made with this instance, with `FplError::AuthenticationRequired`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `MyTeam` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut fpl = Fpl::new();
+    ///     let user_id = 12345;
+    ///
+    ///     if let Err(err) = fpl.login("email@example.com", "password").await {
+    ///         eprintln!("Error: {}", err);
+    ///         return;
+    ///     }
+    ///
+    ///     match fpl.get_my_team(user_id).await {
+    ///         Ok(my_team) => {
+    ///             // Process the user's squad
+    ///             println!("{:?}", my_team);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// A successful [`login`](struct.Fpl.html#method.login) is required before calling this function.
+    /// The provided `user_id` should be the identifier of the logged in FPL user.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted user IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_my_team", skip(self))
+    )]
+    pub async fn get_my_team(&self, user_id: i64) -> Result<MyTeam, FplError> {
+        Self::check_id("user_id", user_id)?;
+        if !self.authenticated {
+            return Err(FplError::AuthenticationRequired);
+        }
+        let url = format!("{}my-team/{}/", self.base_url, user_id);
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves information about the logged in Fantasy Premier League user.
+    ///
+    /// This gives the entry of the logged in user, which is needed by functions such as
+    /// [`get_my_team`](struct.Fpl.html#method.get_my_team), along with their watchlist.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the logged in user's information on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If no successful login has been made with this instance, with `FplError::AuthenticationRequired`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Me` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut fpl = Fpl::new();
+    ///
+    ///     if let Err(err) = fpl.login("email@example.com", "password").await {
+    ///         eprintln!("Error: {}", err);
+    ///         return;
+    ///     }
+    ///
+    ///     match fpl.get_me().await {
+    ///         Ok(me) => {
+    ///             // Process the logged in user's information
+    ///             println!("{:?}", me);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// A successful [`login`](struct.Fpl.html#method.login) is required before calling this function.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_me", skip_all)
+    )]
+    pub async fn get_me(&self) -> Result<Me, FplError> {
+        if !self.authenticated {
+            return Err(FplError::AuthenticationRequired);
+        }
+        let url = format!("{}me/", self.base_url);
+        self.fetch(url).await
+    }
+
+    /// Asynchronously makes transfers for the logged in Fantasy Premier League user.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - A `TransferPayload` describing the transfers, built with a
+    ///   [`TransferPayloadBuilder`](models/transfer_payload/struct.TransferPayloadBuilder.html).
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` once the transfers are made, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If no successful login has been made with this instance, with `FplError::AuthenticationRequired`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the FPL API rejects the transfers, with `FplError::Api` holding the detail it gave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use fpl_rs::models::transfer_payload::TransferPayloadBuilder;
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut fpl = Fpl::new();
+    ///
+    ///     if let Err(err) = fpl.login("email@example.com", "password").await {
+    ///         eprintln!("Error: {}", err);
+    ///         return;
+    ///     }
+    ///
+    ///     let payload = match TransferPayloadBuilder::new(12345, 10)
+    ///         .transfer(2, 1, 55, 50)
+    ///         .build()
+    ///     {
+    ///         Ok(payload) => payload,
+    ///         Err(err) => {
+    ///             eprintln!("Error: {}", err);
+    ///             return;
+    ///         }
+    ///     };
+    ///
+    ///     match fpl.make_transfers(payload).await {
+    ///         Ok(()) => {
+    ///             // The transfers are made
+    ///             println!("Transfers made");
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// A successful [`login`](struct.Fpl.html#method.login) is required before calling this function.
+    /// The transfers are final once made, unless the deadline has not passed and they are undone on the FPL website.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`get_my_team`](struct.Fpl.html#method.get_my_team)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.make_transfers", skip_all)
+    )]
+    pub async fn make_transfers(&self, payload: TransferPayload) -> Result<(), FplError> {
+        if !self.authenticated {
+            return Err(FplError::AuthenticationRequired);
+        }
+        let url = format!("{}transfers/", self.base_url);
+        self.post(url, &payload).await
+    }
+
+    /// Asynchronously saves the picks of the squad of the logged in Fantasy Premier League user.
+    ///
+    /// This saves the bench order, the captain and the vice captain.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry_id` - An `i64` representing the unique identifier of the logged in FPL user.
+    /// * `picks` - A `Vec<MyTeamPickUpdate>` of the 15 picks of the squad.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` once the squad is saved, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If no successful login has been made with this instance, with `FplError::AuthenticationRequired`.
+    /// - If there is a failure when making the requests to the FPL API.
+    /// - If the FPL API rejects the update, with `FplError::Api` holding the detail it gave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut fpl = Fpl::new();
+    ///     let entry_id = 12345;
+    ///
+    ///     if let Err(err) = fpl.login("email@example.com", "password").await {
+    ///         eprintln!("Error: {}", err);
+    ///         return;
+    ///     }
+    ///
+    ///     match fpl.update_my_team(entry_id, vec![]).await {
+    ///         Ok(()) => {
+    ///             // The squad is updated
+    ///             println!("Squad updated");
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// A successful [`login`](struct.Fpl.html#method.login) is required before calling this function.
+    /// The picks are usually those of [`get_my_team`](struct.Fpl.html#method.get_my_team), changed with
+    /// `MyTeam::set_captain`, `MyTeam::set_vice_captain` or `MyTeam::substitute` and turned into updates
+    /// with `MyTeam::pick_updates`. They are saved as given, without being checked locally.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`get_my_team`](struct.Fpl.html#method.get_my_team)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.update_my_team", skip(self, picks))
+    )]
+    pub async fn update_my_team(
+        &self,
+        entry_id: i64,
+        picks: Vec<MyTeamPickUpdate>,
+    ) -> Result<(), FplError> {
+        if !self.authenticated {
+            return Err(FplError::AuthenticationRequired);
+        }
+        let url = format!("{}my-team/{}/", self.base_url, entry_id);
+        let body = serde_json::json!({ "picks": picks, "chip": null });
+        self.post(url, &body).await
+    }
+
+    /// Asynchronously makes a player of the starting XI captain of the squad of the logged in Fantasy Premier League user.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry_id` - An `i64` representing the unique identifier of the logged in FPL user.
+    /// * `player_id` - An `i64` representing the unique identifier of the player to make captain.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` once the squad is saved, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If no successful login has been made with this instance, with `FplError::AuthenticationRequired`.
+    /// - If the player is not in the squad or is on the bench.
+    /// - If there is a failure when making the requests to the FPL API.
+    /// - If the FPL API rejects the update, with `FplError::Api` holding the detail it gave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut fpl = Fpl::new();
+    ///     let entry_id = 12345;
+    ///
+    ///     if let Err(err) = fpl.login("email@example.com", "password").await {
+    ///         eprintln!("Error: {}", err);
+    ///         return;
+    ///     }
+    ///
+    ///     match fpl.set_captain(entry_id, 1).await {
+    ///         Ok(()) => {
+    ///             // The squad is updated
+    ///             println!("Squad updated");
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// A successful [`login`](struct.Fpl.html#method.login) is required before calling this function.
+    /// The squad is retrieved, changed with `MyTeam::set_captain` and saved.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`update_my_team`](struct.Fpl.html#method.update_my_team)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.set_captain", skip(self))
+    )]
+    pub async fn set_captain(&self, entry_id: i64, player_id: i64) -> Result<(), FplError> {
+        let mut my_team = self.get_my_team(entry_id).await?;
+        my_team.set_captain(player_id)?;
+        self.update_my_team(entry_id, my_team.pick_updates()).await
+    }
+
+    /// Asynchronously makes a player of the starting XI vice captain of the squad of the logged in Fantasy Premier League user.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry_id` - An `i64` representing the unique identifier of the logged in FPL user.
+    /// * `player_id` - An `i64` representing the unique identifier of the player to make vice captain.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` once the squad is saved, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If no successful login has been made with this instance, with `FplError::AuthenticationRequired`.
+    /// - If the player is not in the squad or is on the bench.
+    /// - If there is a failure when making the requests to the FPL API.
+    /// - If the FPL API rejects the update, with `FplError::Api` holding the detail it gave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut fpl = Fpl::new();
+    ///     let entry_id = 12345;
+    ///
+    ///     if let Err(err) = fpl.login("email@example.com", "password").await {
+    ///         eprintln!("Error: {}", err);
+    ///         return;
+    ///     }
+    ///
+    ///     match fpl.set_vice_captain(entry_id, 1).await {
+    ///         Ok(()) => {
+    ///             // The squad is updated
+    ///             println!("Squad updated");
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// A successful [`login`](struct.Fpl.html#method.login) is required before calling this function.
+    /// The squad is retrieved, changed with `MyTeam::set_vice_captain` and saved.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`update_my_team`](struct.Fpl.html#method.update_my_team)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.set_vice_captain", skip(self))
+    )]
+    pub async fn set_vice_captain(&self, entry_id: i64, player_id: i64) -> Result<(), FplError> {
+        let mut my_team = self.get_my_team(entry_id).await?;
+        my_team.set_vice_captain(player_id)?;
+        self.update_my_team(entry_id, my_team.pick_updates()).await
+    }
+
+    /// Asynchronously swaps two players of the squad of the logged in Fantasy Premier League user, such as a starting player and a substitute.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry_id` - An `i64` representing the unique identifier of the logged in FPL user.
+    /// * `player_out` - An `i64` representing the unique identifier of the player leaving their position.
+    /// * `player_in` - An `i64` representing the unique identifier of the player taking their position.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` once the squad is saved, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If no successful login has been made with this instance, with `FplError::AuthenticationRequired`.
+    /// - If a player is not in the squad.
+    /// - If the captain or vice captain would be moved to the bench.
+    /// - If the starting XI would not have exactly one goalkeeper, at least three defenders and at least one forward.
+    /// - If there is a failure when making the requests to the FPL API.
+    /// - If the FPL API rejects the update, with `FplError::Api` holding the detail it gave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut fpl = Fpl::new();
+    ///     let entry_id = 12345;
+    ///
+    ///     if let Err(err) = fpl.login("email@example.com", "password").await {
+    ///         eprintln!("Error: {}", err);
+    ///         return;
+    ///     }
+    ///
+    ///     match fpl.substitute(entry_id, 1, 2).await {
+    ///         Ok(()) => {
+    ///             // The squad is updated
+    ///             println!("Squad updated");
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// A successful [`login`](struct.Fpl.html#method.login) is required before calling this function.
+    /// The squad is retrieved, changed with `MyTeam::substitute` and saved. The positions of the players
+    /// are looked up in the static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`update_my_team`](struct.Fpl.html#method.update_my_team)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.substitute", skip(self))
+    )]
+    pub async fn substitute(
+        &self,
+        entry_id: i64,
+        player_out: i64,
+        player_in: i64,
+    ) -> Result<(), FplError> {
+        let element_types: HashMap<i64, i64> = self
+            .get_all_players()
+            .await?
+            .into_iter()
+            .map(|player| (player.id, player.element_type))
+            .collect();
+        let mut my_team = self.get_my_team(entry_id).await?;
+        my_team.substitute(player_out, player_in, &element_types)?;
+        self.update_my_team(entry_id, my_team.pick_updates()).await
+    }
+
+    /// Asynchronously retrieves the players on the watchlist of the logged in Fantasy Premier League user.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the players on the watchlist on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If no successful login has been made with this instance, with `FplError::AuthenticationRequired`.
+    /// - If there is a failure when making the requests to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON responses into the `Me` or `BootstrapStatic` types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut fpl = Fpl::new();
+    ///
+    ///     if let Err(err) = fpl.login("email@example.com", "password").await {
+    ///         eprintln!("Error: {}", err);
+    ///         return;
+    ///     }
+    ///
+    ///     match fpl.get_watchlist().await {
+    ///         Ok(watchlist) => {
+    ///             // Process the players on the watchlist
+    ///             println!("{:?}", watchlist);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// A successful [`login`](struct.Fpl.html#method.login) is required before calling this function.
+    /// The players are looked up in the cached static data, in the order of the watchlist.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`get_me`](struct.Fpl.html#method.get_me)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_watchlist", skip_all)
+    )]
+    pub async fn get_watchlist(&self) -> Result<Players, FplError> {
+        let watched = self.get_me().await?.watched;
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(watched
+            .iter()
+            .filter_map(|&player_id| bootstrap_static.player(player_id).cloned())
+            .collect())
+    }
+
+    /// Asynchronously adds a player to the watchlist of the logged in Fantasy Premier League user.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - An `i64` representing the unique identifier of the FPL player.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` once the player is on the watchlist, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If no successful login has been made with this instance, with `FplError::AuthenticationRequired`.
+    /// - If there is a failure when making the requests to the FPL API.
+    /// - If the FPL API rejects the request, with `FplError::Api` holding the detail it gave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut fpl = Fpl::new();
+    ///
+    ///     if let Err(err) = fpl.login("email@example.com", "password").await {
+    ///         eprintln!("Error: {}", err);
+    ///         return;
+    ///     }
+    ///
+    ///     match fpl.add_to_watchlist(1).await {
+    ///         Ok(()) => {
+    ///             // The player is on the watchlist
+    ///             println!("Watchlist updated");
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// A successful [`login`](struct.Fpl.html#method.login) is required before calling this function.
+    /// Adding a player who is already on the watchlist does nothing.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`get_me`](struct.Fpl.html#method.get_me)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.add_to_watchlist", skip(self))
+    )]
+    pub async fn add_to_watchlist(&self, player_id: i64) -> Result<(), FplError> {
+        if self.get_me().await?.watched.contains(&player_id) {
+            return Ok(());
+        }
+        let url = format!("{}watchlist/{}/", self.base_url, player_id);
+        self.post(url, &serde_json::json!({})).await
+    }
+
+    /// Asynchronously removes a player from the watchlist of the logged in Fantasy Premier League user.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - An `i64` representing the unique identifier of the FPL player.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` once the player is off the watchlist, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If no successful login has been made with this instance, with `FplError::AuthenticationRequired`.
+    /// - If there is a failure when making the requests to the FPL API.
+    /// - If the FPL API rejects the request, with `FplError::Api` holding the detail it gave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut fpl = Fpl::new();
+    ///
+    ///     if let Err(err) = fpl.login("email@example.com", "password").await {
+    ///         eprintln!("Error: {}", err);
+    ///         return;
+    ///     }
+    ///
+    ///     match fpl.remove_from_watchlist(1).await {
+    ///         Ok(()) => {
+    ///             // The player is off the watchlist
+    ///             println!("Watchlist updated");
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// A successful [`login`](struct.Fpl.html#method.login) is required before calling this function.
+    /// Removing a player who is not on the watchlist does nothing.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`get_me`](struct.Fpl.html#method.get_me)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.remove_from_watchlist", skip(self))
+    )]
+    pub async fn remove_from_watchlist(&self, player_id: i64) -> Result<(), FplError> {
+        if !self.get_me().await?.watched.contains(&player_id) {
+            return Ok(());
+        }
+        let url = format!("{}watchlist/{}/", self.base_url, player_id);
+        self.delete(url).await
+    }
+
+    /// Asynchronously retrieves information about a Fantasy Premier League team.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - An `i64` representing the unique identifier of the FPL team.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with team information on success, or an `FplError` on failure.
+    ///
+    /// If the team with the specified ID is not found, it returns `Ok(None)`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Team` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let team_id = 6789;
+    ///
+    ///     match fpl.get_team(team_id).await {
+    ///         Ok(Some(team)) => {
+    ///             // Process the team information
+    ///             println!("{:?}", team);
+    ///         }
+    ///         Ok(None) => {
+    ///             // Handle the case when the team is not found
+    ///             println!("Team not found");
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `team_id` should be a valid identifier of an existing FPL team.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted team IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_team", skip(self))
+    )]
+    pub async fn get_team(&self, team_id: i64) -> Result<Option<Team>, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static.team(team_id).cloned())
+    }
+
+    /// Asynchronously retrieves information about multiple Fantasy Premier League teams.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_ids` - A `Vec<i64>` containing unique identifiers of the FPL teams.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with team information for the specified team IDs on success,
+    /// or an `FplError` on failure.
+    ///
+    /// If the provided `team_ids` is empty, it returns information about all FPL teams.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Team` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let team_ids = vec![123, 456, 789];
+    ///
+    ///     match fpl.get_teams(team_ids).await {
+    ///         Ok(teams) => {
+    ///             // Process the team information
+    ///             println!("{:?}", teams);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `team_ids` should be valid identifiers of existing FPL teams.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted team IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_teams", skip(self))
+    )]
+    pub async fn get_teams(&self, team_ids: Vec<i64>) -> Result<Vec<Team>, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        match team_ids {
+            x if x.is_empty() => Ok(bootstrap_static.teams.clone()),
+            t_ids => Ok(bootstrap_static
+                .teams
+                .iter()
+                .filter(|team| t_ids.contains(&team.id))
+                .cloned()
+                .collect()),
+        }
+    }
+
+    /// Asynchronously resolves the teams playing a Fantasy Premier League fixture.
+    ///
+    /// # Arguments
+    ///
+    /// * `fixture` - A reference to the `Fixture` whose teams to resolve.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the home team and the away team of the fixture on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If a team of the fixture is not in the static data, with `FplError::NotFound`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let fixture = fpl.get_fixture(1).await.unwrap_or_default();
+    ///
+    ///     match fpl.resolve_fixture_teams(&fixture).await {
+    ///         Ok((home, away)) => {
+    ///             // Process the teams of the fixture
+    ///             println!("{} vs {}", home.name, away.name);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The teams are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.resolve_fixture_teams", skip_all)
+    )]
+    pub async fn resolve_fixture_teams(&self, fixture: &Fixture) -> Result<(Team, Team), FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        let team = |team_id| {
+            bootstrap_static
+                .team(team_id)
+                .cloned()
+                .ok_or(FplError::NotFound)
+        };
+        Ok((team(fixture.team_h)?, team(fixture.team_a)?))
+    }
+
+    /// Asynchronously describes a Fantasy Premier League fixture with the names of its teams and its gameweek,
+    /// such as `Arsenal vs Chelsea (GW5)`.
+    ///
+    /// Fixtures which are not scheduled yet are described as `Arsenal vs Chelsea (unscheduled)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `fixture` - A reference to the `Fixture` to describe.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the description of the fixture on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If a team of the fixture is not in the static data, with `FplError::NotFound`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let fixture = fpl.get_fixture(1).await.unwrap_or_default();
+    ///
+    ///     match fpl.describe_fixture(&fixture).await {
+    ///         Ok(description) => {
+    ///             // Print the description of the fixture
+    ///             println!("{:?}", description);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The teams are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.describe_fixture", skip_all)
+    )]
+    pub async fn describe_fixture(&self, fixture: &Fixture) -> Result<String, FplError> {
+        let (home, away) = self.resolve_fixture_teams(fixture).await?;
+        Ok(match fixture.event {
+            Some(gameweek_id) => format!("{} vs {} (GW{})", home.name, away.name, gameweek_id),
+            None => format!("{} vs {} (unscheduled)", home.name, away.name),
+        })
+    }
+
+    /// Asynchronously retrieves information about all Fantasy Premier League teams.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with information about all FPL teams on success,
+    /// or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Team` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_all_teams().await {
+    ///         Ok(teams) => {
+    ///             // Process information about all FPL teams
+    ///             println!("{:?}", teams);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_all_teams", skip_all)
+    )]
+    pub async fn get_all_teams(&self) -> Result<Vec<Team>, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static.teams.clone())
+    }
+
+    /// Asynchronously retrieves a Premier League team by its three-letter code, such as `ARS`.
+    ///
+    /// The code is matched case-insensitively against the `short_name` of the teams.
+    ///
+    /// # Arguments
+    ///
+    /// * `short_name` - A `&str` containing the three-letter code of the team.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the team on success, or an `FplError` on failure.
+    ///
+    /// If no team has that code, it returns `Ok` with `None`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_team_by_short_name("ARS").await {
+    ///         Ok(team) => {
+    ///             // Process the team
+    ///             println!("{:?}", team);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The teams are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_team_by_short_name", skip(self))
+    )]
+    pub async fn get_team_by_short_name(&self, short_name: &str) -> Result<Option<Team>, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static
+            .teams
+            .iter()
+            .find(|team| team.short_name.eq_ignore_ascii_case(short_name))
+            .cloned())
+    }
+
+    /// Asynchronously retrieves the set piece notes of every Fantasy Premier League team.
+    ///
+    /// The notes describe who is on penalties, free kicks and corners for each team.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the set piece notes on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `SetPieceNotes` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_set_piece_notes().await {
+    ///         Ok(set_piece_notes) => {
+    ///             // Process the set piece notes
+    ///             println!("{:?}", set_piece_notes);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_set_piece_notes", skip_all)
+    )]
+    pub async fn get_set_piece_notes(&self) -> Result<SetPieceNotes, FplError> {
+        let url = format!("{}team/set-piece-notes/", self.base_url);
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves the set piece notes of a Fantasy Premier League team.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - An `i64` representing the unique identifier of the FPL team.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the team's set piece notes on success, or an `FplError` on failure.
+    ///
+    /// If the team with the specified ID is not found, it returns `Ok(None)`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `SetPieceNotes` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let team_id = 1;
+    ///
+    ///     match fpl.get_team_set_piece_notes(team_id).await {
+    ///         Ok(team_set_piece_notes) => {
+    ///             // Process the team's set piece notes
+    ///             println!("{:?}", team_set_piece_notes);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `team_id` should be a valid identifier of an existing FPL team.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted team IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_team_set_piece_notes", skip(self))
+    )]
+    pub async fn get_team_set_piece_notes(
+        &self,
+        team_id: i64,
+    ) -> Result<Option<TeamSetPieceNotes>, FplError> {
+        let set_piece_notes = self.get_set_piece_notes().await?;
+        Ok(set_piece_notes
+            .teams
+            .into_iter()
+            .find(|team| team_id == team.id))
+    }
+
+    /// Asynchronously retrieves information about a Fantasy Premier League player.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - An `i64` representing the unique identifier of the FPL player.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with player information on success, or an `FplError` on failure.
+    ///
+    /// If the player with the specified ID is not found, it returns `Ok(None)`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Player` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let player_id = 12345;
+    ///
+    ///     match fpl.get_player(player_id).await {
+    ///         Ok(Some(player)) => {
+    ///             // Process player information
+    ///             println!("{:?}", player);
+    ///         }
+    ///         Ok(None) => {
+    ///             // Handle the case when the player is not found
+    ///             println!("Player not found");
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `player_id` should be a valid identifier of an existing FPL player.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted player IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_player", skip(self))
+    )]
+    pub async fn get_player(&self, player_id: i64) -> Result<Option<Player>, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static.player(player_id).cloned())
+    }
+
+    /// Asynchronously retrieves information about a Fantasy Premier League player by their code.
+    ///
+    /// Unlike its id, which changes every season, the code of a player stays the same across seasons,
+    /// and some data, such as the photos of the players, references players by code.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - An `i64` representing the code of the FPL player.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with player information on success, or an `FplError` on failure.
+    ///
+    /// If the player with the specified code is not found, it returns `Ok(None)`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Player` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let code = 223340;
+    ///
+    ///     match fpl.get_player_by_code(code).await {
+    ///         Ok(Some(player)) => {
+    ///             // Process player information
+    ///             println!("{:?}", player);
+    ///         }
+    ///         Ok(None) => {
+    ///             // Handle the case when the player is not found
+    ///             println!("Player not found");
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `code` should be the code of an existing FPL player.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted player codes or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_player_by_code", skip(self))
+    )]
+    pub async fn get_player_by_code(&self, code: i64) -> Result<Option<Player>, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static.player_by_code(code).cloned())
+    }
+
+    /// Asynchronously retrieves the detailed summary of a Fantasy Premier League player.
+    ///
+    /// The summary contains the player's per-gameweek history for the current season,
+    /// their totals for previous seasons and their upcoming fixtures.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - An `i64` representing the unique identifier of the FPL player.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the player summary on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If `player_id` is not positive, with `FplError::InvalidArgument`.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `PlayerSummary` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let player_id = 355;
+    ///
+    ///     match fpl.get_player_summary(player_id).await {
+    ///         Ok(player_summary) => {
+    ///             // Process the player summary
+    ///             println!("{:?}", player_summary.history);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `player_id` should be a valid identifier of an existing FPL player.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted player IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_player_summary", skip(self))
+    )]
+    pub async fn get_player_summary(&self, player_id: i64) -> Result<PlayerSummary, FplError> {
+        Self::check_id("player_id", player_id)?;
+        let url = format!("{}element-summary/{}/", self.base_url, player_id);
+        self.fetch(url).await
+    }
+
+    /// Asynchronously retrieves the summaries of several Fantasy Premier League players.
+    ///
+    /// The requests are made concurrently, at most as many at once as configured with
+    /// [`FplBuilder::concurrency`](fpl_builder/struct.FplBuilder.html#method.concurrency) (8 by default).
+    ///
+    /// # Arguments
+    ///
+    /// * `player_ids` - A `Vec<i64>` of the unique identifiers of the FPL players.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the id of every player, in the order of `player_ids`, along with the result of retrieving
+    /// their summary, on success, or an `FplError` on failure.
+    ///
+    /// When the batch failure mode is `BatchFailureMode::FailFast` (the default), every result returned is `Ok`.
+    /// When it is `BatchFailureMode::Partial`, the results of the summaries which could not be retrieved are the
+    /// errors of their requests.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If the batch failure mode is `BatchFailureMode::FailFast` (the default) and any of the requests fails, with the error of the first failed request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let player_ids = vec![1, 2, 3];
+    ///
+    ///     match fpl.get_player_summaries(player_ids).await {
+    ///         Ok(summaries) => {
+    ///             for (player_id, summary) in summaries {
+    ///                 // Process the player's summary
+    ///                 println!("{}: {:?}", player_id, summary);
+    ///             }
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `player_ids` should be valid identifiers of existing FPL players.
+    /// The batch failure mode is configured with [`FplBuilder::batch_failure_mode`](fpl_builder/struct.FplBuilder.html#method.batch_failure_mode).
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted player IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_player_summaries", skip(self))
+    )]
+    pub async fn get_player_summaries(
+        &self,
+        player_ids: Vec<i64>,
+    ) -> Result<PlayerSummaries, FplError> {
+        let mut results = stream::iter(player_ids)
+            .map(|player_id| async move { (player_id, self.get_player_summary(player_id).await) })
+            .buffered(self.concurrency);
+        let mut summaries = Vec::new();
+        while let Some((player_id, result)) = results.next().await {
+            match result {
+                Err(err) if self.batch_failure_mode == BatchFailureMode::FailFast => {
+                    return Err(err)
+                }
+                result => summaries.push((player_id, result)),
+            }
+        }
+        Ok(summaries)
+    }
+
+    /// Asynchronously retrieves information about multiple Fantasy Premier League players.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_ids` - A `Vec<i64>` containing unique identifiers of the FPL players.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with player information for the specified player IDs on success,
+    /// or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Players` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let player_ids = vec![12345, 111];
+    ///
+    ///     match fpl.get_players(player_ids).await {
+    ///         Ok(players) => {
+    ///             // Process players information
+    ///             println!("{:?}", players);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The provided `player_ids` should be a list of valid identifiers of existing FPL players.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when passing untrusted player IDs or relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_players", skip(self))
+    )]
+    pub async fn get_players(&self, player_ids: Vec<i64>) -> Result<Players, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+
+        Ok(bootstrap_static
+            .elements
+            .iter()
+            .filter(|element| player_ids.contains(&element.id))
+            .cloned()
+            .collect::<Players>())
+    }
+
+    /// Asynchronously retrieves information about all Fantasy Premier League players.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with information about all FPL players on success,
+    /// or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Players` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_all_players().await {
+    ///         Ok(players) => {
+    ///             // Process information about all FPL players
+    ///             println!("{:?}", players);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_all_players", skip_all)
+    )]
+    pub async fn get_all_players(&self) -> Result<Players, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static.elements.clone())
+    }
+
+    /// Asynchronously retrieves the Fantasy Premier League players matching a filter.
+    ///
+    /// The players can be filtered by position, price, team and minutes played.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - A `PlayerFilter` holding the criteria the players have to match.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the players matching every criterion of the filter on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// use fpl_rs::models::bootstrap_static::Position;
+    /// use fpl_rs::models::player_filter::PlayerFilter;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let filter = PlayerFilter::new()
+    ///         .position(Position::Defender)
+    ///         .max_price(4.5);
+    ///
+    ///     match fpl.get_players_filtered(filter).await {
+    ///         Ok(players) => {
+    ///             // Process the matching players
+    ///             println!("{:?}", players);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The players are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_players_filtered", skip_all)
+    )]
+    pub async fn get_players_filtered(&self, filter: PlayerFilter) -> Result<Players, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static
+            .elements
+            .iter()
+            .filter(|player| filter.matches(player))
+            .cloned()
+            .collect())
+    }
+
+    /// Asynchronously retrieves a Fantasy Premier League player by the name displayed for them, such as `Salah`.
+    ///
+    /// The name is matched case-insensitively against the `web_name` of the players.
+    ///
+    /// # Arguments
+    ///
+    /// * `web_name` - A `&str` containing the displayed name of the player.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the first player with that name on success, or an `FplError` on failure.
+    ///
+    /// If no player has that name, it returns `Ok` with `None`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_player_by_web_name("Salah").await {
+    ///         Ok(player) => {
+    ///             // Process the player
+    ///             println!("{:?}", player);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The players are looked up in the cached static data.
+    /// Displayed names are not unique, use [`get_players_by_web_name`](struct.Fpl.html#method.get_players_by_web_name)
+    /// to retrieve every player with a name.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_player_by_web_name", skip(self))
+    )]
+    pub async fn get_player_by_web_name(&self, web_name: &str) -> Result<Option<Player>, FplError> {
+        let players = self.get_players_by_web_name(web_name).await?;
+        Ok(players.into_iter().next())
+    }
+
+    /// Asynchronously retrieves every Fantasy Premier League player displayed with a name, such as `Silva`.
+    ///
+    /// The name is matched case-insensitively against the `web_name` of the players.
+    ///
+    /// # Arguments
+    ///
+    /// * `web_name` - A `&str` containing the displayed name of the players.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the players with that name on success, or an `FplError` on failure.
+    ///
+    /// If no player has that name, it returns `Ok` with an empty `Vec`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_players_by_web_name("Silva").await {
+    ///         Ok(players) => {
+    ///             // Process the players
+    ///             println!("{:?}", players);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The players are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_players_by_web_name", skip(self))
+    )]
+    pub async fn get_players_by_web_name(&self, web_name: &str) -> Result<Players, FplError> {
+        let web_name = web_name.to_lowercase();
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static
+            .elements
+            .iter()
+            .filter(|player| player.web_name.to_lowercase() == web_name)
+            .cloned()
+            .collect())
+    }
+
+    /// Asynchronously retrieves every Fantasy Premier League player of a team.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - An `i64` representing the unique identifier of the team.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the players of the team on success, or an `FplError` on failure.
+    ///
+    /// If the team has no players, such as when the id is not the id of a team, it returns `Ok` with an empty `Vec`.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let team_id = 1;
+    ///
+    ///     match fpl.get_players_by_team(team_id).await {
+    ///         Ok(players) => {
+    ///             // Process the players of the team
+    ///             println!("{:?}", players);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The players are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_players_by_team", skip(self))
+    )]
+    pub async fn get_players_by_team(&self, team_id: i64) -> Result<Players, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static
+            .elements
+            .iter()
+            .filter(|player| player.team == team_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Asynchronously retrieves every Fantasy Premier League player of a team, grouped by position.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - An `i64` representing the unique identifier of the team.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the players of the team for each position on success, or an `FplError` on failure.
+    ///
+    /// Positions without players are left out, and so are players without a known position.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let team_id = 1;
+    ///
+    ///     match fpl.get_squad_for_team(team_id).await {
+    ///         Ok(squad) => {
+    ///             // Process the players of the team for each position
+    ///             println!("{:?}", squad);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The players are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_squad_for_team", skip(self))
+    )]
+    pub async fn get_squad_for_team(
+        &self,
+        team_id: i64,
+    ) -> Result<HashMap<Position, Players>, FplError> {
+        let mut squad: HashMap<Position, Players> = HashMap::new();
+        for player in self.get_players_by_team(team_id).await? {
+            if let Some(position) = player.position() {
+                squad.entry(position).or_default().push(player);
+            }
+        }
+        Ok(squad)
+    }
+
+    /// Asynchronously resolves the picks of a Fantasy Premier League user into the players picked.
+    ///
+    /// # Arguments
+    ///
+    /// * `picks` - A slice of `Pick` representing the picks to resolve, such as the `picks` of `UserPicks`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with every pick paired with its player, in the order of `picks`, on success,
+    /// or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If a pick is of a player which is not in the static data, with `FplError::UnknownPlayers` listing every such id.
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///     let picks = fpl
+    ///         .get_user_picks(12345, 1)
+    ///         .await
+    ///         .map(|user_picks| user_picks.picks)
+    ///         .unwrap_or_default();
+    ///
+    ///     match fpl.resolve_picks(&picks).await {
+    ///         Ok(picks) => {
+    ///             // Process the picked players
+    ///             println!("{:?}", picks);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The players are looked up in the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.resolve_picks", skip_all)
+    )]
+    pub async fn resolve_picks(&self, picks: &[Pick]) -> Result<Vec<(Pick, Player)>, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        let mut resolved = Vec::with_capacity(picks.len());
+        let mut unknown = Vec::new();
+        for pick in picks {
+            match bootstrap_static.player(pick.element) {
+                Some(player) => resolved.push((pick.clone(), player.clone())),
+                None => unknown.push(pick.element),
+            }
+        }
+        if !unknown.is_empty() {
+            return Err(FplError::UnknownPlayers { ids: unknown });
+        }
+        Ok(resolved)
+    }
+
+    /// Asynchronously retrieves information about static gameweeks in the Fantasy Premier League.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with information about static gameweeks on success,
+    /// or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `Vec<Event>` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_static_gameweeks().await {
+    ///         Ok(gameweeks) => {
+    ///             // Process information about static gameweeks
+    ///             println!("{:?}", gameweeks);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_static_gameweeks", skip_all)
+    )]
+    pub async fn get_static_gameweeks(&self) -> Result<Vec<Event>, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static.events.clone())
+    }
+
+    /// Asynchronously retrieves the number of managers registered in the Fantasy Premier League.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the number of registered managers on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_total_players().await {
+    ///         Ok(total_players) => {
+    ///             // Display the number of registered managers
+    ///             println!("{:?}", total_players);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    /// The number is read from the cached static data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_total_players", skip_all)
+    )]
+    pub async fn get_total_players(&self) -> Result<i64, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static.total_players)
+    }
+
+    /// Asynchronously retrieves static data from the Fantasy Premier League API.
+    ///
+    /// This function is typically used to fetch data that does not change frequently,
+    /// such as player details, team information, and gameweek details.
+    ///
+    /// The data is cached and reused until it is older than the configured time to live
+    /// (one hour by default, see [`FplBuilder::bootstrap_ttl`](fpl_builder/struct.FplBuilder.html#method.bootstrap_ttl)),
+    /// after which it is fetched again.
+    ///
+    /// The cached data is shared with the clones of this instance, and concurrent calls finding no fresh data
+    /// fetch it only once, so an instance can be shared, such as behind an `Arc`, without fetching it repeatedly.
+    /// Once cached, the data is fetched again with the `ETag` and `Last-Modified` headers of the response it came with,
+    /// so that the FPL API only sends it again if it has changed, which
+    /// [`MetricsSnapshot::not_modified`](metrics/struct.MetricsSnapshot.html#structfield.not_modified) counts.
+    /// This function returns a copy of the whole data, which is several megabytes large: prefer the methods
+    /// looking up players, teams or gameweeks in it, which only copy what they return.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with static data on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.get_bootstrap_static().await {
+    ///         Ok(bootstrap_static) => {
+    ///             // Process static data from the FPL API
+    ///             println!("{:?}", bootstrap_static);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.get_bootstrap_static", skip_all)
+    )]
+    pub async fn get_bootstrap_static(&self) -> Result<BootstrapStatic, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(BootstrapStatic::clone(&bootstrap_static))
+    }
+
+    /// Asynchronously retrieves the cached static data, fetching it if it is not cached or too old.
+    ///
+    /// See [`get_bootstrap_static`](struct.Fpl.html#method.get_bootstrap_static), which this function
+    /// behaves like, except for sharing the cached data instead of copying it, so that the methods
+    /// looking data up in it only copy what they return.
+    async fn cached_bootstrap_static(&self) -> Result<Arc<StaticData>, FplError> {
+        self.bootstrap_static
+            .get_or_fetch(
+                |_, fetched_at| fetched_at.elapsed() < self.bootstrap_ttl,
+                |validators| self.fetch_static_data(validators),
+            )
+            .await
+    }
+
+    /// Asynchronously retrieves the player the gameweek with the id `gameweek_id` names in the field
+    /// `player_id` returns, if the gameweek is found and names one.
+    async fn gameweek_player(
+        &self,
+        gameweek_id: i64,
+        player_id: impl Fn(&Event) -> Option<i64>,
+    ) -> Result<Option<Player>, FplError> {
+        let bootstrap_static = self.cached_bootstrap_static().await?;
+        Ok(bootstrap_static
+            .events
+            .iter()
+            .find(|gameweek| gameweek.id == gameweek_id)
+            .and_then(player_id)
+            .and_then(|player_id| bootstrap_static.player(player_id))
+            .cloned())
+    }
+
+    /// Asynchronously fetches the static data and indexes it, unless it has not changed since
+    /// `validators` were sent.
+    async fn fetch_static_data(
+        &self,
+        validators: Option<Validators>,
+    ) -> Result<Conditional<Arc<StaticData>>, FplError> {
+        let url = format!("{}bootstrap-static/", self.base_url);
+        let bootstrap_static = self
+            .fetch_conditional(url, &RequestOptions::default(), validators.as_ref())
+            .await?;
+        #[cfg(not(target_arch = "wasm32"))]
+        if let (Some(disk_cache), Conditional::Modified(bootstrap_static, _)) =
+            (&self.disk_cache, &bootstrap_static)
+        {
+            disk_cache
+                .save(BOOTSTRAP_STATIC_FILE, bootstrap_static)
+                .await;
+        }
+        Ok(bootstrap_static.map(|bootstrap_static| Arc::new(StaticData::new(bootstrap_static))))
+    }
+
+    /// Asynchronously fetches the static data from the Fantasy Premier League API, ignoring any cached copy.
+    ///
+    /// The fetched data replaces the cached copy. Unlike [`invalidate_cache`](struct.Fpl.html#method.invalidate_cache),
+    /// which only discards the cached copy so that the next request needing static data fetches it lazily,
+    /// this function fetches the data eagerly, for example right after the daily price changes.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the freshly fetched static data on success, or an `FplError` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an `FplError` in the following cases:
+    /// - If there is a failure when making the request to the FPL API.
+    /// - If the HTTP response status code is not OK (200).
+    /// - If there is an error deserializing the JSON response into the `BootstrapStatic` type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let fpl = Fpl::new();
+    ///
+    ///     match fpl.refresh_bootstrap_static().await {
+    ///         Ok(bootstrap_static) => {
+    ///             // Process the freshly fetched static data
+    ///             println!("{:?}", bootstrap_static);
+    ///         }
+    ///         Err(err) => {
+    ///             // Handle the error
+    ///             eprintln!("Error: {}", err);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function utilizes the `fetch` method internally to make a request to the FPL API.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if there is an internal error during HTTP request processing.
+    ///
+    /// # Safety
+    ///
+    /// This function is marked as `unsafe` due to its reliance on external data (HTTP responses).
+    /// Use caution when relying on FPL API data.
+    ///
+    /// # See Also
+    ///
+    /// - [`fetch`](struct.Fpl.html#method.fetch)
+    /// - [Fantasy Premier League API Documentation](https://fantasy.premierleague.com/api)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "fpl.refresh_bootstrap_static", skip_all)
+    )]
+    pub async fn refresh_bootstrap_static(&self) -> Result<BootstrapStatic, FplError> {
+        let bootstrap_static = self
+            .bootstrap_static
+            .fetch(|validators| self.fetch_static_data(validators))
+            .await?;
+        Ok(BootstrapStatic::clone(&bootstrap_static))
+    }
+
+    /// Discards the cached static data and fixtures, of this instance and its clones.
+    ///
+    /// No request is made: the static data and fixtures are fetched again the next time they are needed.
+    /// Use [`refresh_bootstrap_static`](struct.Fpl.html#method.refresh_bootstrap_static)
+    /// to fetch it again right away instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// let fpl = Fpl::new();
+    /// fpl.invalidate_cache();
+    /// ```
+    pub fn invalidate_cache(&self) {
+        self.bootstrap_static.clear();
+        self.fixtures.clear();
+    }
+
+    /// Discards the cached static data and fixtures, of this instance and its clones.
+    ///
+    /// This is the same as [`invalidate_cache`](struct.Fpl.html#method.invalidate_cache).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::Fpl;
+    ///
+    /// let fpl = Fpl::new();
+    /// fpl.clear_cache();
+    /// ```
+    pub fn clear_cache(&self) {
+        self.invalidate_cache();
+    }
+
+    /// Removes the static data and fixtures saved to the cache directory configured with
+    /// [`FplBuilder::cache_dir`](fpl_builder/struct.FplBuilder.html#method.cache_dir), if any.
+    ///
+    /// The data cached in memory is kept, see [`invalidate_cache`](struct.Fpl.html#method.invalidate_cache).
+    ///
+    /// # Errors
+    ///
+    /// This function returns `FplError::Other` if a saved file cannot be removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fpl_rs::{fpl_error::FplError, Fpl};
+    ///
+    /// fn main() -> Result<(), FplError> {
+    ///     let fpl = Fpl::builder().cache_dir(std::env::temp_dir().join("fpl")).build()?;
+    ///     fpl.invalidate_disk_cache()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn invalidate_disk_cache(&self) -> Result<(), FplError> {
+        match &self.disk_cache {
+            Some(disk_cache) => disk_cache.clear(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Deserializes the body of a response from `url` into the provided type.
+///
+/// # Errors
+///
+/// This function returns `FplError::Deserialize` if the body cannot be deserialized, with the path of
+/// the offending field and the start of the body, so that changes to the FPL API can be diagnosed.
+fn deserialize_body<T>(url: &str, body: &str) -> Result<T, FplError>
+where
+    T: DeserializeOwned,
+{
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let snippet = match body.char_indices().nth(MAX_BODY_SNIPPET_CHARS) {
+            Some((end, _)) => format!("{}...", &body[..end]),
+            None => body.to_string(),
+        };
+        FplError::Deserialize(format!(
+            "Failed when deserializing response from: {} at: {} with this error: {} and this body: {}",
+            url,
+            err.path(),
+            err.inner(),
+            snippet
+        ))
+    })
+}
+
+/// Returns the explanation the FPL API gives in the body of an error response, either its `detail`
+/// field or the first error it lists, such as in `non_field_errors`.
+fn error_detail(body: &str) -> Option<String> {
+    let json: serde_json::Value = serde_json::from_str(body).ok()?;
+    if let Some(detail) = json.get("detail") {
+        return Some(
+            detail
+                .as_str()
+                .map_or_else(|| detail.to_string(), String::from),
+        );
+    }
+    let errors = json.as_object()?;
+    errors
+        .get("non_field_errors")
+        .into_iter()
+        .chain(errors.values())
+        .find_map(|field_errors| field_errors.as_array()?.first()?.as_str())
+        .map(String::from)
+}
+
+/// Returns whether a body is the message the FPL API answers with while the game is being updated,
+/// either as plain text or as the detail of a JSON error.
+fn is_game_updating(body: &str) -> bool {
+    body.to_lowercase().contains("the game is being updated")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::models::dream_team::DreamTeamPick;
+    use crate::models::my_team::MyTeamPick;
+
+    /// Returns a mock server answering the static data with `bootstrap_static`, expecting it to be
+    /// fetched once, and an instance making its requests to it.
+    async fn fpl_with_static(bootstrap_static: impl Serialize) -> (MockServer, Fpl) {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bootstrap-static/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(bootstrap_static))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        (mock_server, fpl)
+    }
+
+    #[tokio::test]
+    async fn test_get_user() {
+        let fpl = Fpl::new();
+        let user_id = 5489342;
+        match fpl.get_user(user_id).await {
+            Ok(user) => assert_eq!(user.id, user_id),
+            Err(e) => panic!("Got this guy: {}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_bootstrap_static() {
+        let fpl = Fpl::new();
+        let bootstrap_static = fpl.get_bootstrap_static().await.unwrap();
+        assert!(!bootstrap_static.teams.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_all_teams() {
+        let fpl = Fpl::new();
+        let teams = fpl.get_all_teams().await.unwrap();
+        assert!(teams.len() == 20);
+    }
+
+    #[tokio::test]
+    async fn test_get_teams() {
+        let fpl = Fpl::new();
+        let teams = fpl.get_teams(vec![1, 2]).await.unwrap();
+        assert!(teams.len() == 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_team() {
+        let fpl = Fpl::new();
+        let team = fpl.get_team(2).await.unwrap().unwrap();
+        assert!(team.name == "Aston Villa");
+    }
+
+    #[tokio::test]
+    async fn test_get_fixture() {
+        let fpl = Fpl::new();
+        let fixture = fpl.get_fixture(65).await.unwrap();
+        assert!(fixture.team_h == 14);
+    }
+
+    #[tokio::test]
+    async fn test_get_classic_league() {
+        let fpl = Fpl::new();
+        let classic_league = fpl.get_classic_league(753276).await.unwrap();
+        assert!(classic_league.standings.results.len() == 16);
+    }
+
+    #[tokio::test]
+    async fn test_get_h2h_league() {
+        let fpl = Fpl::new();
+        let h2h_league = fpl.get_h2h_league(288399).await.unwrap();
+        assert!(!h2h_league.results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_live_gameweek() {
+        let fpl = Fpl::new();
+        let live_gameweek = fpl.get_live_gameweek(2).await.unwrap();
+        assert!(live_gameweek.elements.len() == 670);
+    }
+
+    #[tokio::test]
+    async fn test_get_transfers() {
+        let fpl = Fpl::new();
+        let user_id = 5489342;
+        match fpl.get_transfers(user_id).await {
+            Ok(transfers) => assert!(!transfers.is_empty()),
+            Err(e) => panic!("Got this guy: {}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_static_gameweek() {
+        let fpl = Fpl::new();
+        let gameweek_id = 2;
+        let static_gameweek = fpl.get_static_gameweek(gameweek_id).await.unwrap().unwrap();
+        assert!(static_gameweek.id == gameweek_id);
+    }
+
+    #[tokio::test]
+    async fn test_get_user_picks() {
+        let fpl = Fpl::new();
+        let user_id = 5489342;
+        let gameweek_id = 14;
+        let user_picks = fpl.get_user_picks(user_id, gameweek_id).await.unwrap();
+        assert!(user_picks.picks.len() == 15);
+    }
+
+    #[tokio::test]
+    async fn test_get_player_summary() {
+        let fpl = Fpl::new();
+        let player_summary = fpl.get_player_summary(355).await.unwrap();
+        assert!(!player_summary.history.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_entry_transfers() {
+        let fpl = Fpl::new();
+        let user_id = 5489342;
+        match fpl.get_entry_transfers(user_id).await {
+            Ok(transfers) => assert!(transfers.iter().all(|t| t.entry == user_id)),
+            Err(e) => panic!("Got this guy: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_entry_transfers_empty() {
+        let transfers = serde_json::from_str::<Transfers>("[]");
+        assert_eq!(transfers.unwrap(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_get_user_history() {
+        let fpl = Fpl::new();
+        let user_id = 5489342;
+        match fpl.get_user_history(user_id).await {
+            Ok(user_history) => assert!(!user_history.current.is_empty()),
+            Err(e) => panic!("Got this guy: {}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_entry_history() {
+        let fpl = Fpl::new();
+        let user_id = 5489342;
+        let user = fpl.get_user(user_id).await.unwrap();
+        let finished_gameweeks = fpl
+            .get_static_gameweeks()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|gameweek| gameweek.finished && gameweek.id >= user.started_event)
+            .map(|gameweek| gameweek.id)
+            .collect::<Vec<i64>>();
+        let entry_history = fpl.get_entry_history(user_id).await.unwrap();
+        let finished_entries = entry_history
+            .current
+            .iter()
+            .filter(|entry| finished_gameweeks.contains(&entry.event))
+            .count();
+        assert_eq!(finished_entries, finished_gameweeks.len());
+    }
+
+    #[tokio::test]
+    async fn test_get_dream_team() {
+        let fpl = Fpl::new();
+        let dream_team = fpl.get_dream_team(2).await.unwrap();
+        assert!(dream_team.team.len() == 11);
+    }
+
+    #[tokio::test]
+    async fn test_get_dream_team_invalid_gameweek() {
+        let fpl = Fpl::new();
+        assert!(fpl.get_dream_team(0).await.is_err());
+        assert!(fpl.get_dream_team(39).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_dream_team_gameweek_from_static_data() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/dream-team/39/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(DreamTeam {
+                top_player: None,
+                team: vec![DreamTeamPick {
+                    element: 1,
+                    points: 10,
+                    position: 1,
+                }],
+            }))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let bootstrap_static = BootstrapStatic {
+            events: (1..=39)
+                .map(|id| Event {
+                    id,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        fpl.bootstrap_static
+            .set(Arc::new(StaticData::new(bootstrap_static)));
+        let dream_team = fpl.get_dream_team(39).await.unwrap();
+        assert_eq!(dream_team.team.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_dream_team_players_errors() {
+        let bootstrap_static = BootstrapStatic {
+            elements: vec![Player {
+                id: 1,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let (mock_server, fpl) = fpl_with_static(bootstrap_static).await;
+        let pick = |element| DreamTeamPick {
+            element,
+            points: 10,
+            position: 1,
+        };
+        for (gameweek_id, team) in [(1, vec![]), (2, vec![pick(1), pick(7), pick(9)])] {
+            Mock::given(method("GET"))
+                .and(path(format!("/dream-team/{}/", gameweek_id)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(DreamTeam {
+                    top_player: None,
+                    team,
+                }))
+                .mount(&mock_server)
+                .await;
+        }
+
+        assert!(matches!(
+            fpl.get_dream_team_players(1).await,
+            Err(FplError::NotYetAvailable { gameweek: 1 })
+        ));
+        match fpl.get_dream_team_players(2).await {
+            Err(FplError::UnknownPlayers { ids }) => assert_eq!(ids, vec![7, 9]),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_event_status() {
+        let fpl = Fpl::new();
+        let event_status = fpl.get_event_status().await.unwrap();
+        assert!(!event_status.status.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_dream_team_players() {
+        let fpl = Fpl::new();
+        let dream_team_players = fpl.get_dream_team_players(2).await.unwrap();
+        assert!(dream_team_players.len() == 11);
+    }
+
+    #[tokio::test]
+    async fn test_get_set_piece_notes() {
+        let fpl = Fpl::new();
+        let set_piece_notes = fpl.get_set_piece_notes().await.unwrap();
+        assert!(set_piece_notes.teams.len() == 20);
+    }
+
+    #[tokio::test]
+    async fn test_get_team_set_piece_notes() {
+        let fpl = Fpl::new();
+        let team_set_piece_notes = fpl.get_team_set_piece_notes(1).await.unwrap().unwrap();
+        assert!(team_set_piece_notes.id == 1);
+    }
+
+    #[test]
+    fn test_is_authenticated_without_login() {
+        let fpl = Fpl::new();
+        assert!(!fpl.is_authenticated());
+    }
+
+    #[tokio::test]
+    async fn test_get_h2h_league_standings() {
+        let fpl = Fpl::new();
+        let h2h_standings = fpl.get_h2h_league_standings(288399).await.unwrap();
+        assert!(!h2h_standings.standings.results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_my_team_without_login() {
+        let fpl = Fpl::new();
+        assert!(matches!(
+            fpl.get_my_team(5489342).await,
+            Err(FplError::AuthenticationRequired)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_classic_league_page() {
+        let fpl = Fpl::new();
+        let classic_league = fpl.get_classic_league_page(314, 2).await.unwrap();
+        assert!(classic_league.standings.page == 2);
+        assert!(classic_league.standings.results.len() == 50);
+    }
+
+    #[tokio::test]
+    async fn test_get_full_classic_league() {
+        let fpl = Fpl::new();
+        let first_page = fpl.get_classic_league_page(753276, 1).await.unwrap();
+        let classic_league = fpl.get_full_classic_league(753276).await.unwrap();
+        assert!(!classic_league.standings.has_next);
+        assert!(classic_league.standings.results.len() >= first_page.standings.results.len());
+    }
+
+    #[test]
+    fn test_fpl_error_is_std_error() {
+        let error: Box<dyn std::error::Error> = Box::new(FplError::NotFound);
+        assert!(error.source().is_none());
+        assert_eq!(error.to_string(), "FplError: Not found");
+    }
+
+    #[tokio::test]
+    async fn test_get_full_h2h_league() {
+        let fpl = Fpl::new();
+        let first_page = fpl.get_h2h_league_page(288399, 1).await.unwrap();
+        let h2h_league = fpl.get_full_h2h_league(288399).await.unwrap();
+        assert!(!h2h_league.has_next);
+        assert!(h2h_league.results.len() > first_page.results.len());
+    }
+
+    #[tokio::test]
+    async fn test_fpl_error_from_reqwest_error() {
+        let err = Client::new()
+            .get("http://127.0.0.1:1/")
+            .send()
+            .await
+            .unwrap_err();
+        match FplError::from(err) {
+            FplError::Request(err) => assert!(err.url().is_some()),
+            other => panic!("Got this guy: {}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_upcoming_fixtures() {
+        let fpl = Fpl::new();
+        let fixtures = fpl.get_upcoming_fixtures().await.unwrap();
+        assert!(fixtures.iter().all(|fixture| !fixture.finished));
+    }
+
+    #[tokio::test]
+    async fn test_get_finished_fixtures() {
+        let fpl = Fpl::new();
+        let fixtures = fpl.get_finished_fixtures().await.unwrap();
+        assert!(fixtures.iter().all(|fixture| fixture.finished));
+    }
+
+    #[tokio::test]
+    async fn test_builder_timeout() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bootstrap-static/"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(1)))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .timeout(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        match fpl.get_bootstrap_static().await {
+            Err(FplError::Timeout(err)) => assert!(err.is_timeout()),
+            _ => panic!("Expected the request to time out"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_all_classic_league_new_entries() {
+        let fpl = Fpl::new();
+        let first_page = fpl.get_classic_league_new_entries(753276, 1).await.unwrap();
+        let new_entries = fpl
+            .get_all_classic_league_new_entries(753276)
+            .await
+            .unwrap();
+        assert!(new_entries.len() >= first_page.results.len());
+    }
+
+    #[tokio::test]
+    async fn test_builder_base_url() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/entry/5489342/transfers/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let base_url = format!("{}/api", mock_server.uri());
+        let fpl = Fpl::builder().base_url(&base_url).build().unwrap();
+        let transfers = fpl.get_entry_transfers(5489342).await.unwrap();
+        assert!(transfers.is_empty());
+    }
+
+    /// Returns an instance making its requests to the mock server, logged in through it.
+    async fn logged_in_fpl(mock_server: &MockServer) -> Fpl {
+        Mock::given(method("POST"))
+            .and(path("/accounts/login/"))
+            .respond_with(ResponseTemplate::new(302).insert_header(
+                "Location",
+                format!("{}/?state=success", mock_server.uri()).as_str(),
+            ))
+            .mount(mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(mock_server)
+            .await;
+
+        let mut fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .login_url(&format!("{}/accounts/login/", mock_server.uri()))
+            .build()
+            .unwrap();
+        fpl.login("email@example.com", "password").await.unwrap();
+        fpl
+    }
+
+    fn h2h_league_page(event: i64, page: i64, has_next: bool) -> H2HLeague {
+        let result = models::h2h_league::Result {
+            event,
+            ..Default::default()
+        };
+        H2HLeague {
+            has_next,
+            page,
+            results: vec![result.clone(), result],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_h2h_matches_for_gameweek() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/leagues-h2h-matches/league/288399/"))
+            .and(query_param("event", "5"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(h2h_league_page(5, 1, true)))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/leagues-h2h-matches/league/288399/"))
+            .and(query_param("event", "5"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(h2h_league_page(5, 2, false)))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let h2h_league = fpl.get_h2h_matches_for_gameweek(288399, 5).await.unwrap();
+        assert!(h2h_league.results.len() == 4);
+        assert!(h2h_league.results.iter().all(|result| result.event == 5));
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_ttl() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bootstrap-static/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(BootstrapStatic::default()))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .bootstrap_ttl(Duration::ZERO)
+            .build()
+            .unwrap();
+        fpl.get_all_teams().await.unwrap();
+        fpl.get_all_players().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_cached() {
+        let (_mock_server, fpl) = fpl_with_static(BootstrapStatic::default()).await;
+
+        fpl.get_all_teams().await.unwrap();
+        fpl.get_all_players().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_overall_rank_page_for_user() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/leagues-classic/314/standings/"))
+            .and(query_param("page_standings", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ClassicLeague::default()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let user = User {
+            summary_overall_rank: 101,
+            ..Default::default()
+        };
+        fpl.get_overall_rank_page_for_user(&user).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_overall_rank_page_for_user_without_rank() {
+        let fpl = Fpl::new();
+        let user = User::default();
+        let result = fpl.get_overall_rank_page_for_user(&user).await;
+        assert!(matches!(
+            result,
+            Err(FplError::InvalidArgument {
+                name: "summary_overall_rank",
+                value: 0,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_bootstrap_static() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bootstrap-static/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(BootstrapStatic::default()))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        fpl.get_bootstrap_static().await.unwrap();
+        fpl.refresh_bootstrap_static().await.unwrap();
+        fpl.get_bootstrap_static().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_cache() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bootstrap-static/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(BootstrapStatic::default()))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        fpl.get_bootstrap_static().await.unwrap();
+        fpl.invalidate_cache();
+        fpl.get_bootstrap_static().await.unwrap();
+        fpl.clear_cache();
+        fpl.get_bootstrap_static().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_user_latest_transfers() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/entry/1/transfers-latest/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/entry/2/transfers-latest/"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        assert!(fpl.get_user_latest_transfers(1).await.unwrap().is_empty());
+        assert!(fpl.get_user_latest_transfers(2).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_player_summaries() {
+        let mock_server = MockServer::start().await;
+        for player_id in [1, 2] {
+            Mock::given(method("GET"))
+                .and(path(format!("/element-summary/{}/", player_id)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(PlayerSummary::default()))
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/element-summary/3/"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .concurrency(2)
+            .build()
+            .unwrap();
+        assert_eq!(fpl.get_player_summaries(vec![1, 2]).await.unwrap().len(), 2);
+        assert!(fpl.get_player_summaries(vec![1, 2, 3]).await.is_err());
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .batch_failure_mode(BatchFailureMode::Partial)
+            .build()
+            .unwrap();
+        let summaries = fpl.get_player_summaries(vec![1, 3, 2]).await.unwrap();
+        let ids = summaries
+            .iter()
+            .map(|(player_id, summary)| (*player_id, summary.is_ok()))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![(1, true), (3, false), (2, true)]);
+        assert_eq!(
+            summaries[1].1.as_ref().unwrap_err().status(),
+            Some(reqwest::StatusCode::INTERNAL_SERVER_ERROR)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_fixtures_for_team() {
+        let fixture = |id, event, team_h, team_a| Fixture {
+            id,
+            event,
+            team_h,
+            team_a,
+            ..Default::default()
+        };
+        let fixtures = vec![
+            fixture(1, None, 1, 2),
+            fixture(2, Some(3), 3, 1),
+            fixture(3, Some(1), 2, 3),
+            fixture(4, Some(2), 1, 3),
+        ];
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fixtures/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixtures))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let fixtures = fpl.get_fixtures_for_team(1).await.unwrap();
+        let ids: Vec<i64> = fixtures.iter().map(|fixture| fixture.id).collect();
+        assert_eq!(ids, vec![4, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_login_invalid_credentials() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/accounts/login/"))
+            .respond_with(ResponseTemplate::new(302).insert_header(
+                "Location",
+                format!("{}/?state=fail&reason=credentials", mock_server.uri()).as_str(),
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let mut fpl = Fpl::builder()
+            .login_url(&format!("{}/accounts/login/", mock_server.uri()))
+            .build()
+            .unwrap();
+        let result = fpl.login("email@example.com", "wrong").await;
+        assert!(matches!(result, Err(FplError::InvalidCredentials)));
+        assert!(!fpl.is_authenticated());
+    }
+
+    #[tokio::test]
+    async fn test_get_fixture_unknown_id() {
+        let fixtures = vec![Fixture {
+            id: 1,
+            ..Default::default()
+        }];
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fixtures/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixtures))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let result = fpl.get_fixture(2).await;
+        assert!(matches!(result, Err(FplError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_get_me_without_login() {
+        let fpl = Fpl::new();
+        assert!(matches!(
+            fpl.get_me().await,
+            Err(FplError::AuthenticationRequired)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_make_transfers_rejected() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/transfers/"))
+            .respond_with(
+                ResponseTemplate::new(400).set_body_string(r#"{"detail": "Deadline has passed"}"#),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let fpl = logged_in_fpl(&mock_server).await;
+        let payload = TransferPayload::default();
+        match fpl.make_transfers(payload).await {
+            Err(FplError::Api { status, detail }) => {
+                assert_eq!(status, reqwest::StatusCode::BAD_REQUEST);
+                assert!(detail.contains("Deadline has passed"));
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_captain() {
+        let picks = (1..=15)
+            .map(|element| MyTeamPick {
+                element,
+                position: element,
+                multiplier: i64::from(element <= 11),
+                ..Default::default()
+            })
+            .collect();
+        let my_team = MyTeam {
+            picks,
+            ..Default::default()
+        };
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/my-team/1/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(my_team))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/my-team/1/"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = logged_in_fpl(&mock_server).await;
+        fpl.set_captain(1, 5).await.unwrap();
+        assert!(fpl.set_captain(1, 12).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_watchlist() {
+        let me = Me {
+            watched: vec![3, 1],
+            ..Default::default()
+        };
+        let bootstrap_static = BootstrapStatic {
+            elements: (1..=3)
+                .map(|id| Player {
+                    id,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/me/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(me))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/bootstrap-static/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(bootstrap_static))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/watchlist/2/"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/watchlist/3/"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = logged_in_fpl(&mock_server).await;
+        let watchlist = fpl.get_watchlist().await.unwrap();
+        let ids: Vec<i64> = watchlist.iter().map(|player| player.id).collect();
+        assert_eq!(ids, vec![3, 1]);
+        fpl.add_to_watchlist(1).await.unwrap();
+        fpl.add_to_watchlist(2).await.unwrap();
+        fpl.remove_from_watchlist(2).await.unwrap();
+        fpl.remove_from_watchlist(3).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_builder_user_agent_and_headers() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fixtures/"))
+            .and(header("user-agent", "fpl-test"))
+            .and(header("x-test", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut default_headers = reqwest::header::HeaderMap::new();
+        default_headers.insert("x-test", reqwest::header::HeaderValue::from_static("1"));
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .user_agent("fpl-test")
+            .default_headers(default_headers)
+            .connect_timeout(Duration::from_secs(1))
+            .no_proxy()
+            .build()
+            .unwrap();
+        assert!(fpl.get_fixtures().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_current_next_and_previous_gameweek() {
+        let bootstrap_static = BootstrapStatic {
+            events: vec![
+                Event {
+                    id: 1,
+                    is_previous: true,
+                    ..Default::default()
+                },
+                Event {
+                    id: 2,
+                    is_current: true,
+                    ..Default::default()
+                },
+                Event {
+                    id: 3,
+                    is_next: true,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let (_mock_server, fpl) = fpl_with_static(bootstrap_static).await;
+
+        let id = |gameweek: Option<Event>| gameweek.map(|gameweek| gameweek.id);
+        assert_eq!(id(fpl.get_previous_gameweek().await.unwrap()), Some(1));
+        assert_eq!(id(fpl.get_current_gameweek().await.unwrap()), Some(2));
+        assert_eq!(id(fpl.get_next_gameweek().await.unwrap()), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_builder_client() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fixtures/"))
+            .and(header("x-test", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut default_headers = reqwest::header::HeaderMap::new();
+        default_headers.insert("x-test", reqwest::header::HeaderValue::from_static("1"));
+        let client = Client::builder()
+            .default_headers(default_headers)
+            .build()
+            .unwrap();
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .client(client)
+            .build()
+            .unwrap();
+        assert!(fpl.get_fixtures().await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(Fpl::try_new().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_request_options_timeout() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event/1/live"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(Gameweek::default())
+                    .set_delay(Duration::from_millis(500)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let options = RequestOptions::new().timeout(Duration::from_millis(1));
+        let result = fpl.get_live_gameweek_with_options(1, &options).await;
+        assert!(matches!(result, Err(FplError::Timeout(_))));
+        assert!(fpl.get_live_gameweek(1).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_players_by_web_name() {
+        let player = |id, web_name: &str| Player {
+            id,
+            web_name: String::from(web_name),
+            ..Default::default()
+        };
+        let bootstrap_static = BootstrapStatic {
+            elements: vec![player(1, "Silva"), player(2, "Salah"), player(3, "Silva")],
+            ..Default::default()
+        };
+        let (_mock_server, fpl) = fpl_with_static(bootstrap_static).await;
+
+        let salah = fpl.get_player_by_web_name("salah").await.unwrap();
+        assert_eq!(salah.map(|player| player.id), Some(2));
+        assert_eq!(fpl.get_players_by_web_name("SILVA").await.unwrap().len(), 2);
+        assert!(fpl.get_player_by_web_name("Kane").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_blocked() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fixtures/"))
+            .and(header("user-agent", fpl_builder::DEFAULT_USER_AGENT))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .set_body_raw("<html><body>Access denied</body></html>", "text/html"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(
+                ResponseTemplate::new(502)
+                    .set_body_raw("<html><body>Bad Gateway</body></html>", "text/html"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let result = fpl.get_fixtures().await;
+        assert!(matches!(
+            result,
+            Err(FplError::Blocked(reqwest::StatusCode::FORBIDDEN))
+        ));
+        // The error pages of a load balancer are not blocks.
+        assert!(matches!(
+            fpl.get_event_status().await,
+            Err(FplError::HttpStatus(reqwest::StatusCode::BAD_GATEWAY))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_team_by_short_name() {
+        let team = |id, name: &str, short_name: &str| Team {
+            id,
+            name: String::from(name),
+            short_name: String::from(short_name),
+            ..Default::default()
+        };
+        let bootstrap_static = BootstrapStatic {
+            teams: vec![team(1, "Arsenal", "ARS"), team(14, "Man Utd", "MUN")],
+            ..Default::default()
+        };
+        let (_mock_server, fpl) = fpl_with_static(bootstrap_static).await;
+
+        let arsenal = fpl.get_team_by_short_name("ARS").await.unwrap().unwrap();
+        assert_eq!(arsenal.name, "Arsenal");
+        let man_utd = fpl.get_team_by_short_name("mun").await.unwrap().unwrap();
+        assert_eq!(man_utd.id, 14);
+        assert!(fpl.get_team_by_short_name("XYZ").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_raw_and_typed() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EventStatus::default()))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let json = fpl.get_raw("event-status/").await.unwrap();
+        assert!(json.get("leagues").is_some());
+        let event_status: EventStatus = fpl.get_typed("/event-status/").await.unwrap();
+        assert_eq!(event_status, EventStatus::default());
+    }
 
     #[tokio::test]
-    async fn test_get_team() {
-        let mut fpl = Fpl::new();
-        let team = fpl.get_team(2).await.unwrap().unwrap();
-        assert!(team.name == "Aston Villa");
+    async fn test_get_classic_league_all_pages_cap() {
+        let mut classic_league = ClassicLeague::default();
+        classic_league.standings.has_next = true;
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/leagues-classic/1/standings/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(classic_league))
+            .expect(MAX_PAGES as u64)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let classic_league = fpl.get_classic_league_all_pages(1).await.unwrap();
+        assert!(classic_league.standings.has_next);
     }
 
     #[tokio::test]
-    async fn test_get_fixture() {
-        let mut fpl = Fpl::new();
-        let fixture = fpl.get_fixture(65).await.unwrap();
-        assert!(fixture.team_h == 14);
+    async fn test_get_all_classic_league_new_entries_cap() {
+        let mut classic_league = ClassicLeague::default();
+        classic_league.new_entries.has_next = true;
+        classic_league.new_entries.results.push(NewEntry::default());
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/leagues-classic/1/standings/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(classic_league))
+            .expect(MAX_PAGES as u64)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let new_entries = fpl.get_all_classic_league_new_entries(1).await.unwrap();
+        assert_eq!(new_entries.len(), MAX_PAGES as usize);
     }
 
     #[tokio::test]
-    async fn test_get_classic_league() {
+    async fn test_get_h2h_league_all() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/leagues-h2h-matches/league/288399/"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(h2h_league_page(1, 1, true)))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/leagues-h2h-matches/league/288399/"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(h2h_league_page(2, 2, false)))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let h2h_league = fpl.get_h2h_league_all(288399).await.unwrap();
+        assert!(h2h_league.results.len() > h2h_league_page(1, 1, true).results.len());
+        assert!(!h2h_league.has_next);
+    }
+
+    #[test]
+    fn test_default() {
+        let fpl = Fpl::default();
+        assert_eq!(fpl.base_url, fpl_builder::DEFAULT_BASE_URL);
+        assert!(fpl.bootstrap_static.read(|_, _| ()).is_none());
+    }
+
+    #[test]
+    fn test_debug_does_not_print_bootstrap_static() {
         let fpl = Fpl::new();
-        let classic_league = fpl.get_classic_league(753276).await.unwrap();
-        assert!(classic_league.standings.results.len() == 16);
+        assert!(format!("{:?}", fpl).contains("bootstrap_static_cached: false"));
+
+        let bootstrap_static = BootstrapStatic {
+            elements: vec![Player::default(); 700],
+            teams: vec![Team::default(); 20],
+            ..Default::default()
+        };
+        fpl.bootstrap_static
+            .set(Arc::new(StaticData::new(bootstrap_static)));
+        let debug = format!("{:?}", fpl);
+        assert!(debug.contains("bootstrap_static_cached: true"));
+        assert!(debug.contains("players: 700"));
+        assert!(debug.contains("teams: 20"));
+        assert!(debug.len() < 2048);
     }
 
     #[tokio::test]
-    async fn test_get_h2h_league() {
-        let fpl = Fpl::new();
-        let h2h_league = fpl.get_h2h_league(288399).await.unwrap();
-        assert!(!h2h_league.results.is_empty());
+    async fn test_get_classic_league_with_pages() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/leagues-classic/98765/standings/"))
+            .and(query_param("page_standings", "2"))
+            .and(query_param("page_new_entries", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ClassicLeague::default()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        fpl.get_classic_league_with_pages(98765, 2, 1)
+            .await
+            .unwrap();
     }
 
     #[tokio::test]
-    async fn test_get_live_gameweek() {
-        let fpl = Fpl::new();
-        let live_gameweek = fpl.get_live_gameweek(2).await.unwrap();
-        assert!(live_gameweek.elements.len() == 670);
+    async fn test_observer() {
+        #[derive(Default)]
+        struct Recorder {
+            calls: Mutex<Vec<String>>,
+        }
+
+        impl request_observer::RequestObserver for Arc<Recorder> {
+            fn before(&self, url: &str) {
+                self.calls.lock().unwrap().push(format!("before {}", url));
+            }
+
+            fn after(&self, url: &str, status: Option<reqwest::StatusCode>, _elapsed: Duration) {
+                self.calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("after {} {:?}", url, status));
+            }
+        }
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/entry/1/"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let recorder = Arc::new(Recorder::default());
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .observer(recorder.clone())
+            .build()
+            .unwrap();
+        assert!(fpl.get_user(1).await.is_err());
+
+        let url = format!("{}/entry/1/", mock_server.uri());
+        assert_eq!(
+            *recorder.calls.lock().unwrap(),
+            vec![
+                format!("before {}", url),
+                format!("after {} Some(404)", url)
+            ]
+        );
     }
 
+    #[cfg(feature = "tracing")]
     #[tokio::test]
-    async fn test_get_transfers() {
-        let fpl = Fpl::new();
-        let user_id = 5489342;
-        match fpl.get_transfers(user_id).await {
-            Ok(transfers) => assert!(transfers.len() > 0),
-            Err(e) => panic!("Got this guy: {}", e),
+    async fn test_tracing_spans() {
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        #[derive(Default)]
+        struct SpanRecorder {
+            spans: Arc<Mutex<Vec<String>>>,
+        }
+
+        struct SpanDescription(String);
+
+        impl Visit for SpanDescription {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push_str(&format!(" {}={:?}", field.name(), value));
+            }
+        }
+
+        impl Subscriber for SpanRecorder {
+            fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+                metadata.target().starts_with(env!("CARGO_CRATE_NAME"))
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut spans = self.spans.lock().unwrap();
+                let mut description = SpanDescription(String::from(span.metadata().name()));
+                span.record(&mut description);
+                spans.push(description.0);
+                Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, _event: &Event<'_>) {}
+
+            fn enter(&self, _span: &Id) {}
+
+            fn exit(&self, _span: &Id) {}
         }
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event/1/live"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(Gameweek::default()))
+            .mount(&mock_server)
+            .await;
+
+        let recorder = SpanRecorder::default();
+        let spans = recorder.spans.clone();
+        let _guard = tracing::subscriber::set_default(recorder);
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        fpl.get_live_gameweek(1).await.unwrap();
+
+        assert_eq!(
+            *spans.lock().unwrap(),
+            vec![
+                String::from("fpl.get_live_gameweek gameweek_id=1"),
+                String::from("fpl.fetch path=\"event/1/live\""),
+            ]
+        );
     }
 
     #[tokio::test]
-    async fn test_get_static_gameweek() {
-        let mut fpl = Fpl::new();
-        let gameweek_id = 2;
-        let static_gameweek = fpl.get_static_gameweek(gameweek_id).await.unwrap().unwrap();
-        assert!(static_gameweek.id == gameweek_id);
+    async fn test_metrics() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/entry/1/"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EventStatus::default()))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .collect_metrics()
+            .build()
+            .unwrap();
+        assert_eq!(fpl.metrics(), MetricsSnapshot::default());
+
+        let clone = fpl.clone();
+        assert!(fpl.get_user(1).await.is_err());
+        clone.get_event_status().await.unwrap();
+
+        let metrics = fpl.metrics();
+        assert_eq!(metrics.requests_total, 2);
+        assert_eq!(metrics.requests_failed, 1);
+        assert!(metrics.bytes_received > 0);
+        assert!(metrics.min_latency <= metrics.average_latency);
+        assert!(metrics.average_latency <= metrics.max_latency);
+        assert_eq!(clone.metrics(), metrics);
+    }
+
+    #[test]
+    fn test_error_status() {
+        assert!(FplError::NotFound.is_not_found());
+        assert!(!FplError::NotFound.is_rate_limited());
+        assert_eq!(
+            FplError::NotFound.status(),
+            Some(reqwest::StatusCode::NOT_FOUND)
+        );
+
+        let rate_limited = FplError::HttpStatus(reqwest::StatusCode::TOO_MANY_REQUESTS);
+        assert!(rate_limited.is_rate_limited());
+        assert!(FplError::Blocked(reqwest::StatusCode::TOO_MANY_REQUESTS).is_rate_limited());
+
+        let api = FplError::Api {
+            status: reqwest::StatusCode::BAD_REQUEST,
+            detail: String::from("Invalid transfer"),
+        };
+        assert_eq!(api.status(), Some(reqwest::StatusCode::BAD_REQUEST));
+        assert_eq!(FplError::from("Something went wrong").status(), None);
     }
 
     #[tokio::test]
-    async fn test_get_user_picks() {
-        let fpl = Fpl::new();
-        let user_id = 5489342;
-        let gameweek_id = 14;
-        let user_picks = fpl.get_user_picks(user_id, gameweek_id).await.unwrap();
-        assert!(user_picks.picks.len() == 15);
+    async fn test_rate_limit() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EventStatus::default()))
+            .expect(6)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .rate_limit(2, Duration::from_secs(1))
+            .build()
+            .unwrap();
+        let start = Instant::now();
+        let results = futures::future::join_all((0..6).map(|_| {
+            let fpl = fpl.clone();
+            async move { fpl.get_event_status().await }
+        }))
+        .await;
+        assert!(results.iter().all(Result::is_ok));
+        // 2 requests are made at once, then the other 4 every half a second.
+        assert!(start.elapsed() >= Duration::from_millis(1900));
+    }
+
+    #[tokio::test]
+    async fn test_max_retries() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EventStatus::default()))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .max_retries(2)
+            .base_delay(Duration::from_millis(1))
+            .collect_metrics()
+            .build()
+            .unwrap();
+        fpl.get_event_status().await.unwrap();
+        assert_eq!(fpl.metrics().requests_total, 3);
+        assert_eq!(fpl.metrics().requests_failed, 2);
+    }
+
+    #[tokio::test]
+    async fn test_max_retries_not_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/entry/1/"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .max_retries(3)
+            .base_delay(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        assert!(matches!(fpl.get_user(1).await, Err(FplError::NotFound)));
+    }
+
+    #[test]
+    fn test_is_game_updating() {
+        assert!(is_game_updating("The game is being updated."));
+        assert!(is_game_updating(r#""The game is being updated.""#));
+        assert!(is_game_updating(
+            r#"{"detail": "The game is being updated."}"#
+        ));
+        assert!(!is_game_updating(r#"{"detail": "Service unavailable"}"#));
+    }
+
+    #[tokio::test]
+    async fn test_game_updating() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(503).set_body_json("The game is being updated."))
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EventStatus::default()))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let result = fpl.get_event_status().await;
+        assert!(matches!(result, Err(FplError::GameUpdating)));
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .wait_for_game_update(Duration::from_millis(500))
+            .build()
+            .unwrap();
+        fpl.get_event_status().await.unwrap();
+    }
+
+    #[test]
+    fn test_deserialize_body_error() {
+        let body = r#"{"status": [{"bonus_added": "yes"}], "leagues": ""}"#;
+        let err = deserialize_body::<EventStatus>("https://example.com/event-status/", body)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("https://example.com/event-status/"));
+        assert!(err.contains("status[0].bonus_added"));
+        assert!(err.contains(body));
+
+        let body = format!(r#"["{}"]"#, "é".repeat(1000));
+        let err = deserialize_body::<EventStatus>("https://example.com/event-status/", &body)
+            .unwrap_err()
+            .to_string();
+        let snippet = format!(r#"["{}..."#, "é".repeat(MAX_BODY_SNIPPET_CHARS - 2));
+        assert!(err.ends_with(&snippet));
+    }
+
+    #[tokio::test]
+    async fn test_retry_after() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EventStatus::default()))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        fpl.get_event_status().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/entry/1/"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3600"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let err = fpl.get_event_status().await.unwrap_err();
+        assert!(err.is_rate_limited());
+        assert!(matches!(
+            err,
+            FplError::RateLimited {
+                retry_after: Some(Duration::ZERO)
+            }
+        ));
+        assert!(matches!(
+            fpl.get_user(1).await,
+            Err(FplError::RateLimited {
+                retry_after: Some(retry_after)
+            }) if retry_after == Duration::from_secs(3600)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_requests_per_second() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EventStatus::default()))
+            .expect(5)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .requests_per_second(4)
+            .build()
+            .unwrap();
+        let start = Instant::now();
+        for _ in 0..5 {
+            fpl.get_event_status().await.unwrap();
+        }
+        // 4 requests are made at once, then the fifth a quarter of a second later.
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_error_detail() {
+        assert_eq!(
+            error_detail(r#"{"detail": "Not found."}"#).as_deref(),
+            Some("Not found.")
+        );
+        assert_eq!(
+            error_detail(r#"{"non_field_errors": ["Invalid transfer"]}"#).as_deref(),
+            Some("Invalid transfer")
+        );
+        assert_eq!(
+            error_detail(r#"{"event": ["Event is not valid"]}"#).as_deref(),
+            Some("Event is not valid")
+        );
+        assert_eq!(error_detail("Bad Request"), None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_error_detail() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/entry/1/"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({"detail": "Not found."})),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/entry/2/"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({"detail": "entry not found"})),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/me/"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(
+                serde_json::json!({"detail": "Authentication credentials were not provided."}),
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .set_body_json(serde_json::json!({"detail": "Request was throttled."})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        assert!(matches!(fpl.get_user(1).await, Err(FplError::NotFound)));
+        match fpl.get_user(2).await {
+            Err(err @ FplError::Api { .. }) => {
+                assert!(err.is_not_found());
+                assert!(err.to_string().contains("entry not found"));
+            }
+            other => panic!("Expected an API error, got {:?}", other),
+        }
+        match fpl.get_raw("me/").await {
+            Err(FplError::Api { status, detail }) => {
+                assert_eq!(status, reqwest::StatusCode::FORBIDDEN);
+                assert_eq!(detail, "Authentication credentials were not provided.");
+            }
+            other => panic!("Expected an API error, got {:?}", other),
+        }
+        assert!(fpl.get_event_status().await.unwrap_err().is_rate_limited());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_argument() {
+        let mock_server = MockServer::start().await;
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        assert!(matches!(
+            fpl.get_live_gameweek(0).await,
+            Err(FplError::InvalidArgument {
+                name: "gameweek_id",
+                value: 0,
+                ..
+            })
+        ));
+        assert!(matches!(
+            fpl.get_user_picks(-1, 1).await,
+            Err(FplError::InvalidArgument {
+                name: "user_id",
+                ..
+            })
+        ));
+        assert_eq!(
+            fpl.get_gameweek_fixtures(39).await.unwrap_err().to_string(),
+            "FplError: Invalid gameweek_id: 39, expected a gameweek id between 1 and 38"
+        );
+
+        // The bounds come from the cached static data when there is any.
+        let bootstrap_static = BootstrapStatic {
+            events: (1..=39)
+                .map(|id| Event {
+                    id,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        fpl.bootstrap_static
+            .set(Arc::new(StaticData::new(bootstrap_static)));
+        assert!(fpl.check_gameweek_id(39).is_ok());
+        assert!(mock_server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_fixture_unscheduled() {
+        let fixtures = vec![Fixture {
+            id: 1,
+            event: None,
+            ..Default::default()
+        }];
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fixtures/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixtures))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let fixture = fpl.get_fixture(1).await.unwrap();
+        assert_eq!(fixture.event, None);
+        assert!(matches!(
+            fpl.get_fixture(9999).await,
+            Err(FplError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_squad_for_team() {
+        let player = |id, team, element_type| Player {
+            id,
+            team,
+            element_type,
+            ..Default::default()
+        };
+        let bootstrap_static = BootstrapStatic {
+            elements: vec![
+                player(1, 1, 1),
+                player(2, 1, 2),
+                player(3, 1, 2),
+                player(4, 2, 3),
+                player(5, 1, 4),
+            ],
+            ..Default::default()
+        };
+        let (_mock_server, fpl) = fpl_with_static(bootstrap_static).await;
+
+        let players = fpl.get_players_by_team(1).await.unwrap();
+        assert_eq!(players.len(), 4);
+        assert!(fpl.get_players_by_team(99).await.unwrap().is_empty());
+
+        let squad = fpl.get_squad_for_team(1).await.unwrap();
+        assert_eq!(squad[&Position::Defender].len(), 2);
+        assert_eq!(squad[&Position::Forward][0].id, 5);
+        assert!(!squad.contains_key(&Position::Midfielder));
+    }
+
+    #[tokio::test]
+    async fn test_get_user_picks_not_yet_available() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/entry/12345/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(User {
+                current_event: 10,
+                ..Default::default()
+            }))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/entry/12345/event/10/picks/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(UserPicks::default()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        assert!(matches!(
+            fpl.get_user_picks(12345, 38).await,
+            Err(FplError::NotYetAvailable { gameweek: 38 })
+        ));
+        assert!(matches!(
+            fpl.get_user_picks(12345, 5).await,
+            Err(FplError::NotFound)
+        ));
+        assert!(matches!(
+            fpl.get_user_picks(1, 38).await,
+            Err(FplError::NotFound)
+        ));
+        fpl.get_latest_user_picks(12345).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_resolve_picks() {
+        let bootstrap_static = BootstrapStatic {
+            elements: (1..=3)
+                .map(|id| Player {
+                    id,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let (_mock_server, fpl) = fpl_with_static(bootstrap_static).await;
+        let pick = |element| Pick {
+            element,
+            ..Default::default()
+        };
+
+        let resolved = fpl.resolve_picks(&[pick(3), pick(1)]).await.unwrap();
+        let ids: Vec<(i64, i64)> = resolved
+            .iter()
+            .map(|(pick, player)| (pick.element, player.id))
+            .collect();
+        assert_eq!(ids, vec![(3, 3), (1, 1)]);
+
+        match fpl.resolve_picks(&[pick(2), pick(7), pick(9)]).await {
+            Err(FplError::UnknownPlayers { ids }) => assert_eq!(ids, vec![7, 9]),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(5)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EventStatus::default()))
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .circuit_breaker(5, Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let clone = fpl.clone();
+        for _ in 0..5 {
+            assert_eq!(fpl.circuit_state(), CircuitState::Closed);
+            assert!(matches!(
+                fpl.get_event_status().await,
+                Err(FplError::HttpStatus(_))
+            ));
+        }
+        assert_eq!(clone.circuit_state(), CircuitState::Open);
+        for _ in 0..3 {
+            assert!(matches!(
+                clone.get_event_status().await,
+                Err(FplError::CircuitOpen)
+            ));
+        }
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 5);
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        fpl.get_event_status().await.unwrap();
+        assert_eq!(clone.circuit_state(), CircuitState::Closed);
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_describe_fixture() {
+        let team = |id, name: &str| Team {
+            id,
+            name: name.to_string(),
+            ..Default::default()
+        };
+        let bootstrap_static = BootstrapStatic {
+            teams: vec![team(1, "Arsenal"), team(7, "Chelsea")],
+            ..Default::default()
+        };
+        let (_mock_server, fpl) = fpl_with_static(bootstrap_static).await;
+        let mut fixture = Fixture {
+            event: Some(5),
+            team_h: 1,
+            team_a: 7,
+            ..Default::default()
+        };
+
+        let (home, away) = fpl.resolve_fixture_teams(&fixture).await.unwrap();
+        assert_eq!((home.id, away.id), (1, 7));
+        assert_eq!(
+            fpl.describe_fixture(&fixture).await.unwrap(),
+            "Arsenal vs Chelsea (GW5)"
+        );
+        fixture.event = None;
+        assert_eq!(
+            fpl.describe_fixture(&fixture).await.unwrap(),
+            "Arsenal vs Chelsea (unscheduled)"
+        );
+        fixture.team_a = 99;
+        assert!(matches!(
+            fpl.resolve_fixture_teams(&fixture).await,
+            Err(FplError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fixtures_cache() {
+        let fixture = |id, event, finished| Fixture {
+            id,
+            event: Some(event),
+            finished,
+            ..Default::default()
+        };
+        let fixtures = vec![
+            fixture(1, 1, true),
+            fixture(2, 1, true),
+            fixture(3, 2, false),
+        ];
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fixtures/"))
+            .and(query_param("event", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&fixtures[2..]))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/fixtures/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&fixtures))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        // A finished fixture is fetched once, then reused.
+        assert!(fpl.get_fixture(1).await.unwrap().finished);
+        assert!(fpl.get_fixture(1).await.unwrap().finished);
+        assert_eq!(fpl.get_gameweek_fixtures(1).await.unwrap().len(), 2);
+        // Unfinished fixtures are fetched again.
+        assert!(!fpl.get_fixture(3).await.unwrap().finished);
+        assert_eq!(fpl.get_gameweek_fixtures(2).await.unwrap().len(), 1);
+        assert_eq!(fpl.get_fixtures().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_static_fetched_once_concurrently() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bootstrap-static/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(BootstrapStatic::default())
+                    .set_delay(Duration::from_millis(100)),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Arc::new(Fpl::builder().base_url(&mock_server.uri()).build().unwrap());
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let fpl = Arc::clone(&fpl);
+                tokio::spawn(async move { fpl.get_all_players().await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+        // The clones share the cached data.
+        fpl.as_ref().clone().get_all_teams().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_indexed_lookups() {
+        let bootstrap_static = BootstrapStatic {
+            elements: (1..=200)
+                .map(|id| Player {
+                    id,
+                    code: 1_000 + id,
+                    team: id % 20 + 1,
+                    ..Default::default()
+                })
+                .collect(),
+            teams: (1..=20)
+                .map(|id| Team {
+                    id,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let (_mock_server, fpl) = fpl_with_static(&bootstrap_static).await;
+
+        for id in (0..300).step_by(3) {
+            let expected = bootstrap_static
+                .elements
+                .iter()
+                .find(|player| player.id == id)
+                .cloned();
+            assert_eq!(fpl.get_player(id).await.unwrap(), expected);
+            assert_eq!(fpl.get_player_by_code(1_000 + id).await.unwrap(), expected);
+            let expected = bootstrap_static
+                .teams
+                .iter()
+                .find(|team| team.id == id)
+                .cloned();
+            assert_eq!(fpl.get_team(id).await.unwrap(), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_conditional_requests() {
+        let mock_server = MockServer::start().await;
+        // A response which would fail to deserialize shows that the cached data is used instead.
+        Mock::given(method("GET"))
+            .and(path("/bootstrap-static/"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304).set_body_string("not json"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/bootstrap-static/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(BootstrapStatic {
+                        total_players: 42,
+                        ..Default::default()
+                    }),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .bootstrap_ttl(Duration::ZERO)
+            .collect_metrics()
+            .build()
+            .unwrap();
+        assert_eq!(fpl.get_bootstrap_static().await.unwrap().total_players, 42);
+        assert_eq!(fpl.get_total_players().await.unwrap(), 42);
+        assert_eq!(
+            fpl.refresh_bootstrap_static().await.unwrap().total_players,
+            42
+        );
+        let metrics = fpl.metrics();
+        assert_eq!(metrics.requests_total, 3);
+        assert_eq!(metrics.not_modified, 2);
+        assert_eq!(metrics.requests_failed, 0);
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("fpl_rs_disk_cache_{}", std::process::id()));
+        let team = Team {
+            id: 1,
+            name: String::from("Arsenal"),
+            ..Default::default()
+        };
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bootstrap-static/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(BootstrapStatic {
+                teams: vec![team.clone()],
+                ..Default::default()
+            }))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        let fpl = |base_url: &str| {
+            Fpl::builder()
+                .base_url(base_url)
+                .cache_dir(&cache_dir)
+                .build()
+                .unwrap()
+        };
+
+        assert_eq!(
+            fpl(&mock_server.uri()).get_all_teams().await.unwrap(),
+            vec![team.clone()]
+        );
+        // Nothing listens on the discard port, so the teams must be loaded from the disk.
+        let offline = fpl("http://127.0.0.1:9/");
+        assert_eq!(offline.get_all_teams().await.unwrap(), vec![team.clone()]);
+
+        // Corrupted files are ignored.
+        std::fs::write(cache_dir.join(disk_cache::BOOTSTRAP_STATIC_FILE), "{").unwrap();
+        assert_eq!(
+            fpl(&mock_server.uri()).get_all_teams().await.unwrap(),
+            vec![team]
+        );
+
+        offline.invalidate_disk_cache().unwrap();
+        assert!(!cache_dir.join(disk_cache::BOOTSTRAP_STATIC_FILE).exists());
+        assert!(fpl("http://127.0.0.1:9/").get_all_teams().await.is_err());
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_gameweek_players() {
+        let player = |id| Player {
+            id,
+            web_name: format!("Player {}", id),
+            ..Default::default()
+        };
+        let (_mock_server, fpl) = fpl_with_static(BootstrapStatic {
+            events: vec![
+                Event {
+                    id: 1,
+                    most_captained: Some(1),
+                    most_selected: Some(2),
+                    most_transferred_in: Some(3),
+                    top_element: Some(4),
+                    ..Default::default()
+                },
+                Event {
+                    id: 2,
+                    most_captained: Some(5),
+                    ..Default::default()
+                },
+            ],
+            elements: (1..=4).map(player).collect(),
+            ..Default::default()
+        })
+        .await;
+
+        assert_eq!(
+            fpl.get_most_captained_player(1).await.unwrap(),
+            Some(player(1))
+        );
+        assert_eq!(
+            fpl.get_most_selected_player(1).await.unwrap(),
+            Some(player(2))
+        );
+        assert_eq!(
+            fpl.get_most_transferred_in_player(1).await.unwrap(),
+            Some(player(3))
+        );
+        assert_eq!(fpl.get_top_player(1).await.unwrap(), Some(player(4)));
+        // An unknown player, no player and an unknown gameweek.
+        assert_eq!(fpl.get_most_captained_player(2).await.unwrap(), None);
+        assert_eq!(fpl.get_top_player(2).await.unwrap(), None);
+        assert_eq!(fpl.get_top_player(3).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_compression() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(EventStatus::default()))
+            .mount(&mock_server)
+            .await;
+        let accept_encoding = |compression| {
+            let mock_server = &mock_server;
+            async move {
+                let fpl = Fpl::builder()
+                    .base_url(&mock_server.uri())
+                    .compression(compression)
+                    .build()
+                    .unwrap();
+                fpl.get_event_status().await.unwrap();
+                let requests = mock_server.received_requests().await.unwrap();
+                let accept_encoding = requests
+                    .last()
+                    .unwrap()
+                    .headers
+                    .get(&"accept-encoding".into());
+                accept_encoding.map(|values| {
+                    values
+                        .iter()
+                        .map(|value| value.as_str().to_owned())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+            }
+        };
+
+        let compressed = accept_encoding(true).await.unwrap();
+        assert!(compressed.contains("gzip"), "{}", compressed);
+        assert!(compressed.contains("br"), "{}", compressed);
+        assert_eq!(accept_encoding(false).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_live_gameweek_fetched_once_concurrently() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event/1/live"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(Gameweek::default())
+                    .set_delay(Duration::from_millis(100)),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let fpl = fpl.clone();
+                tokio::spawn(async move { fpl.get_live_gameweek(1).await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+        // The live data is not kept once the request is done.
+        fpl.get_live_gameweek(1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_classic_league_standings_stream() {
+        let page = |page, entries: std::ops::RangeInclusive<i64>, has_next| ClassicLeague {
+            standings: models::classic_league::Standings {
+                has_next,
+                page,
+                results: entries
+                    .map(|entry| models::classic_league::Result {
+                        entry,
+                        ..Default::default()
+                    })
+                    .collect(),
+            },
+            ..Default::default()
+        };
+        let mock_server = MockServer::start().await;
+        for (number, body) in [(1, page(1, 1..=50, true)), (2, page(2, 51..=60, false))] {
+            Mock::given(method("GET"))
+                .and(path("/leagues-classic/98765/standings/"))
+                .and(query_param("page_standings", number.to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+
+        // The second page is only requested once the entries of the first one are consumed.
+        let mut standings = Box::pin(fpl.classic_league_standings_stream(98765));
+        for entry in 1..=50 {
+            assert_eq!(standings.next().await.unwrap().unwrap().entry, entry);
+        }
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+        let rest: Vec<_> = standings.map(|entry| entry.unwrap().entry).collect().await;
+        assert_eq!(rest, (51..=60).collect::<Vec<_>>());
+
+        let errors: Vec<_> = fpl.classic_league_standings_stream(0).collect().await;
+        assert!(matches!(
+            errors[..],
+            [Err(FplError::InvalidArgument { .. })]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fixtures_fetched_once_concurrently() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fixtures/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(vec![Fixture {
+                        id: 1,
+                        finished: false,
+                        ..Default::default()
+                    }])
+                    .set_delay(Duration::from_millis(100)),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder().base_url(&mock_server.uri()).build().unwrap();
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let fpl = fpl.clone();
+                tokio::spawn(async move { fpl.get_fixtures().await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+        // The same goes for refreshing the unfinished fixtures.
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let fpl = fpl.clone();
+                tokio::spawn(async move { fpl.get_fixture(1).await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metrics_compressed_response() {
+        use std::io::Write;
+
+        let body = serde_json::to_vec(&EventStatus::default()).unwrap();
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&body).unwrap();
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/event-status/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-encoding", "gzip")
+                    .set_body_raw(encoder.finish().unwrap(), "application/json"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let fpl = Fpl::builder()
+            .base_url(&mock_server.uri())
+            .collect_metrics()
+            .build()
+            .unwrap();
+        assert_eq!(
+            fpl.get_event_status().await.unwrap(),
+            EventStatus::default()
+        );
+        assert_eq!(fpl.metrics().bytes_received, body.len() as u64);
     }
 }
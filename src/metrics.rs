@@ -0,0 +1,109 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+/// Counters of the requests made by the `Fpl` API wrapper, shared by its clones.
+///
+/// The counters are atomics, so they are updated without locking. Enable them with
+/// [`FplBuilder::collect_metrics`](../fpl_builder/struct.FplBuilder.html#method.collect_metrics)
+/// and read them with [`Fpl::metrics`](../struct.Fpl.html#method.metrics).
+#[derive(Debug)]
+pub struct Metrics {
+    /// How many requests have been made.
+    requests_total: AtomicU64,
+    /// How many requests have failed, either without a response or with an unsuccessful status.
+    requests_failed: AtomicU64,
+    /// How many bytes of response bodies have been read, once decompressed.
+    bytes_received: AtomicU64,
+    /// How many conditional requests have been answered with `304 Not Modified`, so that the cached data was used.
+    not_modified: AtomicU64,
+    /// The sum of the latencies of every request, in microseconds.
+    latency_total_micros: AtomicU64,
+    /// The lowest latency of a request, in microseconds, or `u64::MAX` before any request.
+    latency_min_micros: AtomicU64,
+    /// The highest latency of a request, in microseconds.
+    latency_max_micros: AtomicU64,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Metrics {
+            requests_total: AtomicU64::new(0),
+            requests_failed: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+            not_modified: AtomicU64::new(0),
+            latency_total_micros: AtomicU64::new(0),
+            latency_min_micros: AtomicU64::new(u64::MAX),
+            latency_max_micros: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Metrics {
+    /// Records a request, answered with `status` or failed without a response, which took `elapsed` time.
+    pub(crate) fn record(&self, status: Option<StatusCode>, elapsed: Duration) {
+        self.requests_total.fetch_add(1, Ordering::Relaxed);
+        if status == Some(StatusCode::NOT_MODIFIED) {
+            self.not_modified.fetch_add(1, Ordering::Relaxed);
+        } else if !status.is_some_and(|status| status.is_success()) {
+            self.requests_failed.fetch_add(1, Ordering::Relaxed);
+        }
+        let micros = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        self.latency_total_micros
+            .fetch_add(micros, Ordering::Relaxed);
+        self.latency_min_micros.fetch_min(micros, Ordering::Relaxed);
+        self.latency_max_micros.fetch_max(micros, Ordering::Relaxed);
+    }
+
+    /// Records that a response body of `bytes` bytes, once decompressed, has been read.
+    pub(crate) fn record_body(&self, bytes: usize) {
+        let bytes = u64::try_from(bytes).unwrap_or(u64::MAX);
+        self.bytes_received.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Returns the current value of the counters.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let requests_total = self.requests_total.load(Ordering::Relaxed);
+        if requests_total == 0 {
+            return MetricsSnapshot::default();
+        }
+        let latency_total_micros = self.latency_total_micros.load(Ordering::Relaxed);
+        MetricsSnapshot {
+            requests_total,
+            requests_failed: self.requests_failed.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            not_modified: self.not_modified.load(Ordering::Relaxed),
+            min_latency: Some(Duration::from_micros(
+                self.latency_min_micros.load(Ordering::Relaxed),
+            )),
+            max_latency: Some(Duration::from_micros(
+                self.latency_max_micros.load(Ordering::Relaxed),
+            )),
+            average_latency: Some(Duration::from_micros(latency_total_micros / requests_total)),
+        }
+    }
+}
+
+/// The value of the request counters of the `Fpl` API wrapper at a point in time.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct MetricsSnapshot {
+    /// How many requests have been made.
+    pub requests_total: u64,
+    /// How many requests have failed, either without a response or with an unsuccessful status.
+    pub requests_failed: u64,
+    /// How many bytes of response bodies have been read, once decompressed.
+    ///
+    /// Responses are decompressed as they are read, so this is not the number of bytes transferred
+    /// when they are sent compressed, see [`FplBuilder::compression`](../fpl_builder/struct.FplBuilder.html#method.compression).
+    /// The bodies of responses which are not read, such as those telling that too many requests have been made, are not counted.
+    pub bytes_received: u64,
+    /// How many conditional requests have been answered with `304 Not Modified`, so that the cached data was used.
+    pub not_modified: u64,
+    /// The lowest latency of a request, if any has been made.
+    pub min_latency: Option<Duration>,
+    /// The highest latency of a request, if any has been made.
+    pub max_latency: Option<Duration>,
+    /// The average latency of the requests, if any has been made.
+    pub average_latency: Option<Duration>,
+}
@@ -1,9 +1,11 @@
+use std::collections::HashMap;
 use std::fmt::Display;
 
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::fpl_error::FplError;
 
 pub type Players = Vec<Player>;
 
@@ -19,6 +21,37 @@ pub struct BootstrapStatic {
     pub element_types: Vec<PlayerType>,
 }
 
+impl BootstrapStatic {
+    /// Deserializes static data from JSON, such as a snapshot saved with
+    /// [`to_json_string`](struct.BootstrapStatic.html#method.to_json_string) to be reloaded offline.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `FplError::Deserialize` if `json` is not valid static data,
+    /// with the path of the offending field.
+    pub fn from_json_str(json: &str) -> Result<BootstrapStatic, FplError> {
+        let deserializer = &mut serde_json::Deserializer::from_str(json);
+        serde_path_to_error::deserialize(deserializer).map_err(|err| {
+            FplError::Deserialize(format!(
+                "Failed when deserializing static data at: {} with this error: {}",
+                err.path(),
+                err.inner()
+            ))
+        })
+    }
+
+    /// Serializes the static data into JSON, which
+    /// [`from_json_str`](struct.BootstrapStatic.html#method.from_json_str) reads back.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `FplError::Other` if the data cannot be serialized.
+    pub fn to_json_string(&self) -> Result<String, FplError> {
+        serde_json::to_string(self)
+            .map_err(|err| FplError::Other(format!("Failed when serializing static data: {}", err)))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Event {
     pub id: i64,
@@ -236,9 +269,464 @@ pub struct PlayerType {
     pub element_count: i64,
 }
 
+impl Display for Team {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}, id {})", self.name, self.short_name, self.id)
+    }
+}
+
 impl Display for Player {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let full_name = self.first_name.to_owned() + " " + self.second_name.as_str();
         write!(f, "<id: {}, name: {}>", self.id, full_name)
     }
 }
+
+impl Event {
+    /// Returns how many managers played the chip named `chip` in the gameweek, such as `"bboost"`
+    /// for the bench boost, or `None` if the FPL API gives no count for it.
+    ///
+    /// The names are those [`Chip::name`](../user_picks/enum.Chip.html#method.name) returns.
+    pub fn chip_play_count(&self, chip: &str) -> Option<i64> {
+        self.chip_plays
+            .iter()
+            .find(|chip_play| chip_play.chip_name == chip)
+            .map(|chip_play| chip_play.num_played)
+    }
+
+    /// Returns how many managers played each chip in the gameweek, by chip name.
+    pub fn chip_plays_map(&self) -> HashMap<String, i64> {
+        self.chip_plays
+            .iter()
+            .map(|chip_play| (chip_play.chip_name.clone(), chip_play.num_played))
+            .collect()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Event {
+    /// Returns the deadline of the gameweek, parsed from `deadline_time`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `FplError` if `deadline_time` is not an RFC 3339 date and time.
+    pub fn deadline(&self) -> Result<chrono::DateTime<chrono::Utc>, FplError> {
+        parse_date_time("deadline_time", &self.deadline_time)
+    }
+
+    /// Returns whether the deadline of the gameweek has passed.
+    ///
+    /// A deadline which cannot be parsed is treated as not passed.
+    pub fn is_deadline_passed(&self) -> bool {
+        self.deadline()
+            .is_ok_and(|deadline| deadline <= chrono::Utc::now())
+    }
+}
+
+/// Parses a date and time the FPL API gives as an RFC 3339 string, such as `2023-08-11T17:30:00Z`.
+///
+/// # Errors
+///
+/// This function returns an `FplError` if the string is not an RFC 3339 date and time.
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_date_time(
+    name: &str,
+    value: &str,
+) -> Result<chrono::DateTime<chrono::Utc>, FplError> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|date_time| date_time.with_timezone(&chrono::Utc))
+        .map_err(|err| {
+            FplError::Other(format!(
+                "Failed when parsing {} {:?} as a date and time with this error: {}",
+                name, value, err
+            ))
+        })
+}
+
+impl Player {
+    /// Returns the position of the player, or `None` if its `element_type` is unknown.
+    pub fn position(&self) -> Option<Position> {
+        Position::from_element_type(self.element_type)
+    }
+
+    /// Returns the availability of the player, as given by its `status`.
+    pub fn availability(&self) -> Availability {
+        Availability::from_status(&self.status)
+    }
+
+    /// Returns whether the player is available, without any injury, suspension or other doubt.
+    pub fn is_available(&self) -> bool {
+        self.availability() == Availability::Available
+    }
+
+    /// Returns the current price of the player in millions, `now_cost` being in tenths of a million.
+    pub fn price(&self) -> f64 {
+        self.now_cost as f64 / 10.0
+    }
+
+    /// Returns the current price of the player formatted in millions, such as `£9.5m`.
+    pub fn price_string(&self) -> String {
+        format!("£{:.1}m", self.price())
+    }
+
+    /// Returns how much the price of the player changed since the start of the season, in millions.
+    pub fn price_change_since_start(&self) -> f64 {
+        self.cost_change_start as f64 / 10.0
+    }
+
+    /// Returns the total points of the player this season per million of their current price,
+    /// or `0.0` if the player has no price.
+    pub fn value(&self) -> f64 {
+        self.points_per_million(self.total_points)
+    }
+
+    /// Returns the points of the player in the latest gameweek per million of their current price,
+    /// or `0.0` if the player has no price.
+    pub fn event_value(&self) -> f64 {
+        self.points_per_million(self.event_points)
+    }
+
+    fn points_per_million(&self, points: i64) -> f64 {
+        match self.now_cost {
+            0 => 0.0,
+            _ => points as f64 / self.price(),
+        }
+    }
+
+    /// Returns the form of the player as a number, or `0.0` when it is empty.
+    pub fn form_f64(&self) -> Result<f64, FplError> {
+        parse_stat("form", &self.form)
+    }
+
+    /// Returns the ICT index of the player as a number, or `0.0` when it is empty.
+    pub fn ict_index_f64(&self) -> Result<f64, FplError> {
+        parse_stat("ict_index", &self.ict_index)
+    }
+
+    /// Returns the influence of the player as a number, or `0.0` when it is empty.
+    pub fn influence_f64(&self) -> Result<f64, FplError> {
+        parse_stat("influence", &self.influence)
+    }
+
+    /// Returns the creativity of the player as a number, or `0.0` when it is empty.
+    pub fn creativity_f64(&self) -> Result<f64, FplError> {
+        parse_stat("creativity", &self.creativity)
+    }
+
+    /// Returns the threat of the player as a number, or `0.0` when it is empty.
+    pub fn threat_f64(&self) -> Result<f64, FplError> {
+        parse_stat("threat", &self.threat)
+    }
+
+    /// Returns the value form of the player as a number, or `0.0` when it is empty.
+    pub fn value_form_f64(&self) -> Result<f64, FplError> {
+        parse_stat("value_form", &self.value_form)
+    }
+
+    /// Returns the value season of the player as a number, or `0.0` when it is empty.
+    pub fn value_season_f64(&self) -> Result<f64, FplError> {
+        parse_stat("value_season", &self.value_season)
+    }
+
+    /// Returns the points per game of the player as a number, or `0.0` when it is empty.
+    pub fn points_per_game_f64(&self) -> Result<f64, FplError> {
+        parse_stat("points_per_game", &self.points_per_game)
+    }
+
+    /// Returns the percentage of managers who selected the player as a number, or `0.0` when it is empty.
+    pub fn selected_by_percent_f64(&self) -> Result<f64, FplError> {
+        parse_stat("selected_by_percent", &self.selected_by_percent)
+    }
+}
+
+/// Sorting helpers for a list of players, each sorting in descending order.
+///
+/// The methods consume and return the list, so that they can be chained:
+/// `players.sort_by_form().top_n(10)` returns the 10 players in best form.
+pub trait PlayersExt {
+    /// Sorts the players by total points this season, highest first.
+    fn sort_by_total_points(self) -> Self;
+
+    /// Sorts the players by form, highest first, a form which cannot be parsed counting as `0.0`.
+    fn sort_by_form(self) -> Self;
+
+    /// Sorts the players by total points per million of their current price, highest first.
+    fn sort_by_value(self) -> Self;
+
+    /// Keeps the first `n` players.
+    fn top_n(self, n: usize) -> Self;
+}
+
+impl PlayersExt for Players {
+    fn sort_by_total_points(mut self) -> Self {
+        self.sort_by_key(|player| std::cmp::Reverse(player.total_points));
+        self
+    }
+
+    fn sort_by_form(mut self) -> Self {
+        let form = |player: &Player| player.form_f64().unwrap_or(0.0);
+        self.sort_by(|a, b| form(b).total_cmp(&form(a)));
+        self
+    }
+
+    fn sort_by_value(mut self) -> Self {
+        self.sort_by(|a, b| b.value().total_cmp(&a.value()));
+        self
+    }
+
+    fn top_n(mut self, n: usize) -> Self {
+        self.truncate(n);
+        self
+    }
+}
+
+/// Parses a numeric stat the FPL API gives as a string.
+///
+/// An empty string, given when the stat is not available yet, is read as `0.0`.
+///
+/// # Errors
+///
+/// This function returns an `FplError` if the stat is not a number.
+fn parse_stat(name: &str, value: &str) -> Result<f64, FplError> {
+    if value.is_empty() {
+        return Ok(0.0);
+    }
+    value.parse().map_err(|err| {
+        FplError::Other(format!(
+            "Failed when parsing {} {:?} as a number with this error: {}",
+            name, value, err
+        ))
+    })
+}
+
+/// The availability of a player, as given by the `status` of a `Player`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Availability {
+    Available,
+    Doubtful,
+    Injured,
+    Suspended,
+    Unavailable,
+    NotInSquad,
+}
+
+impl Availability {
+    /// Returns the availability matching a `status`, an unknown status counting as `Unavailable`.
+    pub fn from_status(status: &str) -> Availability {
+        match status {
+            "a" => Availability::Available,
+            "d" => Availability::Doubtful,
+            "i" => Availability::Injured,
+            "s" => Availability::Suspended,
+            "n" => Availability::NotInSquad,
+            _ => Availability::Unavailable,
+        }
+    }
+}
+
+/// The position a player plays in, as given by the `element_type` of a `Player`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Position {
+    Goalkeeper,
+    Defender,
+    Midfielder,
+    Forward,
+}
+
+impl Position {
+    /// Returns the position matching an `element_type`, or `None` if it is unknown.
+    pub fn from_element_type(element_type: i64) -> Option<Position> {
+        match element_type {
+            1 => Some(Position::Goalkeeper),
+            2 => Some(Position::Defender),
+            3 => Some(Position::Midfielder),
+            4 => Some(Position::Forward),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Position::Goalkeeper => "Goalkeeper",
+            Position::Defender => "Defender",
+            Position::Midfielder => "Midfielder",
+            Position::Forward => "Forward",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_event_deadline() {
+        use chrono::{TimeZone, Utc};
+
+        let event = Event {
+            deadline_time: String::from("2023-08-11T17:30:00Z"),
+            ..Default::default()
+        };
+        let deadline = Utc.with_ymd_and_hms(2023, 8, 11, 17, 30, 0).unwrap();
+        assert_eq!(event.deadline().unwrap(), deadline);
+        assert!(event.is_deadline_passed());
+        assert!(Event::default().deadline().is_err());
+    }
+
+    #[test]
+    fn test_event_chip_plays() {
+        let chip_play = |chip_name: &str, num_played| ChipPlay {
+            chip_name: String::from(chip_name),
+            num_played,
+        };
+        let event = Event {
+            chip_plays: vec![chip_play("bboost", 120_000), chip_play("3xc", 85_000)],
+            ..Default::default()
+        };
+        assert_eq!(event.chip_play_count("bboost"), Some(120_000));
+        assert_eq!(event.chip_play_count("3xc"), Some(85_000));
+        assert_eq!(event.chip_play_count("wildcard"), None);
+        assert_eq!(
+            event.chip_plays_map(),
+            HashMap::from([
+                (String::from("bboost"), 120_000),
+                (String::from("3xc"), 85_000)
+            ])
+        );
+        assert!(Event::default().chip_plays_map().is_empty());
+    }
+
+    #[test]
+    fn test_players_ext() {
+        let player = |id, total_points, now_cost, form: &str| Player {
+            id,
+            total_points,
+            now_cost,
+            form: String::from(form),
+            ..Default::default()
+        };
+        let players = vec![
+            player(1, 100, 100, "2.0"),
+            player(2, 120, 130, "6.5"),
+            player(3, 80, 50, "4.0"),
+        ];
+        let ids = |players: Players| players.iter().map(|player| player.id).collect::<Vec<_>>();
+        assert_eq!(ids(players.clone().sort_by_total_points()), vec![2, 1, 3]);
+        assert_eq!(ids(players.clone().sort_by_form()), vec![2, 3, 1]);
+        assert_eq!(ids(players.clone().sort_by_value()), vec![3, 1, 2]);
+        assert_eq!(
+            ids(players.clone().sort_by_total_points().top_n(2)),
+            vec![2, 1]
+        );
+        assert_eq!(players.top_n(5).len(), 3);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let bootstrap_static = BootstrapStatic {
+            events: vec![Event {
+                id: 1,
+                name: String::from("Gameweek 1"),
+                ..Default::default()
+            }],
+            teams: vec![Team {
+                id: 1,
+                name: String::from("Arsenal"),
+                ..Default::default()
+            }],
+            elements: vec![Player {
+                id: 7,
+                form: String::from("5.5"),
+                ..Default::default()
+            }],
+            total_players: 11_000_000,
+            ..Default::default()
+        };
+        let json = bootstrap_static.to_json_string().unwrap();
+        assert_eq!(
+            BootstrapStatic::from_json_str(&json).unwrap(),
+            bootstrap_static
+        );
+
+        let json = json.replacen("\"id\":7", "\"id\":\"7\"", 1);
+        let err = BootstrapStatic::from_json_str(&json).unwrap_err();
+        assert!(matches!(&err, FplError::Deserialize(msg) if msg.contains("elements[0].id")));
+        assert!(BootstrapStatic::from_json_str("").is_err());
+    }
+
+    #[test]
+    fn test_team_display() {
+        let team = Team {
+            id: 1,
+            name: String::from("Arsenal"),
+            short_name: String::from("ARS"),
+            ..Default::default()
+        };
+        assert_eq!(team.to_string(), "Arsenal (ARS, id 1)");
+    }
+
+    #[test]
+    fn test_player_position() {
+        let player = Player {
+            element_type: 3,
+            ..Default::default()
+        };
+        assert_eq!(player.position(), Some(Position::Midfielder));
+        assert_eq!(Position::from_element_type(0), None);
+        assert_eq!(Position::Goalkeeper.to_string(), "Goalkeeper");
+    }
+
+    #[test]
+    fn test_player_availability() {
+        let player = |status: &str| Player {
+            status: String::from(status),
+            ..Default::default()
+        };
+        assert!(player("a").is_available());
+        assert_eq!(player("d").availability(), Availability::Doubtful);
+        assert!(!player("i").is_available());
+        assert_eq!(player("x").availability(), Availability::Unavailable);
+    }
+
+    #[test]
+    fn test_player_price() {
+        let player = Player {
+            now_cost: 95,
+            cost_change_start: -3,
+            ..Default::default()
+        };
+        assert_eq!(player.price(), 9.5);
+        assert_eq!(player.price_string(), "£9.5m");
+        assert_eq!(player.price_change_since_start(), -0.3);
+    }
+
+    #[test]
+    fn test_player_value() {
+        let player = Player {
+            now_cost: 50,
+            total_points: 100,
+            event_points: 6,
+            ..Default::default()
+        };
+        assert_eq!(player.value(), 20.0);
+        assert_eq!(player.event_value(), 1.2);
+        assert_eq!(Player::default().value(), 0.0);
+    }
+
+    #[test]
+    fn test_player_stats_f64() {
+        let player = Player {
+            form: String::from("5.5"),
+            selected_by_percent: String::new(),
+            ict_index: String::from("n/a"),
+            ..Default::default()
+        };
+        assert_eq!(player.form_f64().unwrap(), 5.5);
+        assert_eq!(player.selected_by_percent_f64().unwrap(), 0.0);
+        assert!(player.ict_index_f64().is_err());
+    }
+}
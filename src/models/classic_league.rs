@@ -14,7 +14,16 @@ pub struct ClassicLeague {
 pub struct NewEntries {
     pub has_next: bool,
     pub page: i64,
-    pub results: Vec<Value>,
+    pub results: Vec<NewEntry>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewEntry {
+    pub entry: i64,
+    pub entry_name: String,
+    pub joined_time: String,
+    pub player_first_name: String,
+    pub player_last_name: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
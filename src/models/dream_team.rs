@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DreamTeam {
+    pub top_player: Option<TopPlayer>,
+    pub team: Vec<DreamTeamPick>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopPlayer {
+    pub id: i64,
+    pub points: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DreamTeamPick {
+    pub element: i64,
+    pub points: i64,
+    pub position: i64,
+}
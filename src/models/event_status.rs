@@ -0,0 +1,93 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventStatus {
+    pub status: Vec<DayStatus>,
+    pub leagues: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayStatus {
+    pub bonus_added: bool,
+    pub date: String,
+    pub event: i64,
+    pub points: PointsStatus,
+}
+
+/// The state of the points for a day of the gameweek.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PointsStatus {
+    /// No matches have been played yet.
+    #[default]
+    #[serde(rename = "")]
+    NotStarted,
+    /// Matches are in progress and points are still live.
+    #[serde(rename = "l")]
+    Live,
+    /// Matches are over and points are ready.
+    #[serde(rename = "r")]
+    Ready,
+    /// A state this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+impl EventStatus {
+    /// Returns `true` once bonus points have been added for every day of the gameweek.
+    pub fn all_bonus_added(&self) -> bool {
+        self.status.iter().all(|day| day.bonus_added)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day_status(bonus_added: bool) -> DayStatus {
+        DayStatus {
+            bonus_added,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_all_bonus_added() {
+        let mut event_status = EventStatus {
+            status: vec![day_status(true), day_status(true)],
+            leagues: String::from("Updated"),
+        };
+        assert!(event_status.all_bonus_added());
+
+        event_status.status.push(day_status(false));
+        assert!(!event_status.all_bonus_added());
+    }
+
+    #[test]
+    fn test_deserialize_points_status() {
+        let json = r#"{
+            "status": [
+                {"bonus_added": true, "date": "2023-12-05", "event": 15, "points": "r"},
+                {"bonus_added": false, "date": "2023-12-06", "event": 15, "points": "l"},
+                {"bonus_added": false, "date": "2023-12-07", "event": 15, "points": ""},
+                {"bonus_added": false, "date": "2023-12-08", "event": 15, "points": "x"}
+            ],
+            "leagues": "Updating"
+        }"#;
+        let event_status: EventStatus = serde_json::from_str(json).unwrap();
+        let points = event_status
+            .status
+            .iter()
+            .map(|day| day.points)
+            .collect::<Vec<PointsStatus>>();
+        assert_eq!(
+            points,
+            vec![
+                PointsStatus::Ready,
+                PointsStatus::Live,
+                PointsStatus::NotStarted,
+                PointsStatus::Unknown
+            ]
+        );
+    }
+}
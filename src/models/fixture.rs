@@ -1,6 +1,11 @@
+use std::fmt::Display;
+
 use serde::Deserialize;
 use serde::Serialize;
 
+#[cfg(feature = "chrono")]
+use crate::{fpl_error::FplError, models::bootstrap_static::parse_date_time};
+
 pub type Fixtures = Vec<Fixture>;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -24,6 +29,71 @@ pub struct Fixture {
     pub pulse_id: i64,
 }
 
+impl Fixture {
+    /// Returns whether the team with `team_id` plays the fixture at home,
+    /// or `None` if it does not play the fixture.
+    pub fn is_home_for(&self, team_id: i64) -> Option<bool> {
+        if self.team_h == team_id {
+            Some(true)
+        } else if self.team_a == team_id {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the difficulty of the fixture, from 1 to 5, for the team with `team_id`,
+    /// or `None` if it does not play the fixture.
+    pub fn difficulty_for_team(&self, team_id: i64) -> Option<i64> {
+        self.is_home_for(team_id).map(|is_home| {
+            if is_home {
+                self.team_h_difficulty
+            } else {
+                self.team_a_difficulty
+            }
+        })
+    }
+}
+
+/// Formats the fixture with the ids of its teams, such as `14 2 - 1 7 (GW5)` once it has started,
+/// or `14 TBD 7 (GW5)` before, leaving out the gameweek if the fixture is not scheduled yet.
+///
+/// Use [`Fpl::resolve_fixture_teams`](../../struct.Fpl.html#method.resolve_fixture_teams) or
+/// [`Fpl::describe_fixture`](../../struct.Fpl.html#method.describe_fixture) for the names of the teams.
+impl Display for Fixture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.team_h_score, self.team_a_score) {
+            (Some(team_h_score), Some(team_a_score)) => write!(
+                f,
+                "{} {} - {} {}",
+                self.team_h, team_h_score, team_a_score, self.team_a
+            )?,
+            _ => write!(f, "{} TBD {}", self.team_h, self.team_a)?,
+        }
+        if let Some(event) = self.event {
+            write!(f, " (GW{})", event)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Fixture {
+    /// Returns the kickoff time of the fixture, parsed from `kickoff_time`,
+    /// or `None` if the fixture is not scheduled yet.
+    ///
+    /// While `provisional_start_time` is `true`, the kickoff time may still change.
+    ///
+    /// # Errors
+    ///
+    /// The returned `Result` holds an `FplError` if `kickoff_time` is not an RFC 3339 date and time.
+    pub fn kickoff(&self) -> Option<Result<chrono::DateTime<chrono::Utc>, FplError>> {
+        self.kickoff_time
+            .as_deref()
+            .map(|kickoff_time| parse_date_time("kickoff_time", kickoff_time))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Stat {
     pub identifier: String,
@@ -43,3 +113,54 @@ pub struct H {
     pub element: i64,
 }
 
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "chrono")]
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    #[test]
+    fn test_difficulty_for_team() {
+        let fixture = Fixture {
+            team_h: 1,
+            team_a: 7,
+            team_h_difficulty: 2,
+            team_a_difficulty: 4,
+            ..Default::default()
+        };
+        assert_eq!(fixture.is_home_for(1), Some(true));
+        assert_eq!(fixture.is_home_for(7), Some(false));
+        assert_eq!(fixture.is_home_for(3), None);
+        assert_eq!(fixture.difficulty_for_team(1), Some(2));
+        assert_eq!(fixture.difficulty_for_team(7), Some(4));
+        assert_eq!(fixture.difficulty_for_team(3), None);
+    }
+
+    #[test]
+    fn test_fixture_display() {
+        let mut fixture = Fixture {
+            team_h: 14,
+            team_a: 7,
+            ..Default::default()
+        };
+        assert_eq!(fixture.to_string(), "14 TBD 7");
+        fixture.event = Some(5);
+        assert_eq!(fixture.to_string(), "14 TBD 7 (GW5)");
+        fixture.team_h_score = Some(2);
+        fixture.team_a_score = Some(1);
+        assert_eq!(fixture.to_string(), "14 2 - 1 7 (GW5)");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_fixture_kickoff() {
+        let fixture = Fixture {
+            kickoff_time: Some(String::from("2023-08-11T19:00:00Z")),
+            ..Default::default()
+        };
+        let kickoff = Utc.with_ymd_and_hms(2023, 8, 11, 19, 0, 0).unwrap();
+        assert_eq!(fixture.kickoff().unwrap().unwrap(), kickoff);
+        assert!(Fixture::default().kickoff().is_none());
+    }
+}
@@ -38,3 +38,51 @@ pub struct Result {
     pub knockout_name: String,
 }
 
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct H2HStandings {
+    pub league: League,
+    pub standings: Standings,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct League {
+    pub id: i64,
+    pub name: String,
+    pub created: String,
+    pub closed: bool,
+    pub max_entries: Value,
+    pub league_type: String,
+    pub scoring: String,
+    pub admin_entry: Option<i64>,
+    pub start_event: i64,
+    pub code_privacy: String,
+    pub has_cup: bool,
+    pub cup_league: Value,
+    pub rank: Value,
+    pub ko_rounds: Value,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Standings {
+    pub has_next: bool,
+    pub page: i64,
+    pub results: Vec<Standing>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Standing {
+    pub id: i64,
+    pub division: i64,
+    pub entry: i64,
+    pub player_name: String,
+    pub rank: i64,
+    pub last_rank: i64,
+    pub rank_sort: i64,
+    pub total: i64,
+    pub entry_name: String,
+    pub matches_played: i64,
+    pub matches_won: i64,
+    pub matches_drawn: i64,
+    pub matches_lost: i64,
+    pub points_for: i64,
+}
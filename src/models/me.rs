@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Me {
+    pub player: MePlayer,
+    #[serde(default)]
+    pub watched: Vec<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MePlayer {
+    pub id: i64,
+    pub entry: Option<i64>,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub region: Option<i64>,
+    pub dirty: bool,
+    pub dirty_entry: bool,
+    pub dirty_email: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_me() {
+        let json = r#"{
+            "player": {
+                "first_name": "Jane",
+                "last_name": "Doe",
+                "email": "jane@example.com",
+                "entry": 12345,
+                "region": 241,
+                "dirty": false,
+                "id": 67890
+            },
+            "watched": [4, 301]
+        }"#;
+        let me: Me = serde_json::from_str(json).unwrap();
+        assert_eq!(me.player.entry, Some(12345));
+        assert_eq!(me.watched, vec![4, 301]);
+    }
+}
@@ -8,3 +8,15 @@ pub mod h2h_league;
 pub mod user_picks;
 pub mod transfer;
 
+pub mod player_summary;
+pub mod user_history;
+pub mod dream_team;
+pub mod event_status;
+pub mod set_piece_notes;
+pub mod user_cup;
+pub mod my_team;
+pub mod me;
+pub mod transfer_payload;
+pub mod player_filter;
+
+pub use bootstrap_static::PlayersExt;
@@ -0,0 +1,327 @@
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::fpl_error::FplError;
+use crate::models::bootstrap_static::Position;
+use crate::models::user_picks::Chip;
+
+/// The number of players in the starting XI, the others being on the bench.
+const STARTING_PLAYERS: i64 = 11;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MyTeam {
+    pub picks: Vec<MyTeamPick>,
+    pub chips: Vec<ChipStatus>,
+    pub transfers: TransferState,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MyTeamPick {
+    pub element: i64,
+    pub position: i64,
+    pub selling_price: i64,
+    pub multiplier: i64,
+    pub purchase_price: i64,
+    pub is_captain: bool,
+    pub is_vice_captain: bool,
+}
+
+/// A pick of the squad as sent back to the FPL API when updating it.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MyTeamPickUpdate {
+    pub element: i64,
+    pub position: i64,
+    pub multiplier: i64,
+    pub is_captain: bool,
+    pub is_vice_captain: bool,
+}
+
+impl From<&MyTeamPick> for MyTeamPickUpdate {
+    fn from(pick: &MyTeamPick) -> Self {
+        MyTeamPickUpdate {
+            element: pick.element,
+            position: pick.position,
+            multiplier: pick.multiplier,
+            is_captain: pick.is_captain,
+            is_vice_captain: pick.is_vice_captain,
+        }
+    }
+}
+
+impl MyTeam {
+    /// Returns the picks of the squad as sent back to the FPL API when updating it.
+    pub fn pick_updates(&self) -> Vec<MyTeamPickUpdate> {
+        self.picks.iter().map(MyTeamPickUpdate::from).collect()
+    }
+
+    /// Makes a player of the starting XI captain.
+    ///
+    /// The former captain becomes vice captain if the player was vice captain,
+    /// and keeps the captain multiplier (3 when the triple captain chip is played).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `FplError` if the player is not in the squad or is on the bench.
+    pub fn set_captain(&mut self, player_id: i64) -> Result<(), FplError> {
+        let index = self.starting_pick_index(player_id)?;
+        let captain_multiplier = self
+            .picks
+            .iter()
+            .find(|pick| pick.is_captain)
+            .map_or(2, |pick| pick.multiplier.max(2));
+        let was_vice_captain = self.picks[index].is_vice_captain;
+        for pick in self.picks.iter_mut() {
+            if pick.is_captain {
+                pick.is_captain = false;
+                pick.is_vice_captain = was_vice_captain;
+                pick.multiplier = 1;
+            }
+        }
+        let pick = &mut self.picks[index];
+        pick.is_captain = true;
+        pick.is_vice_captain = false;
+        pick.multiplier = captain_multiplier;
+        Ok(())
+    }
+
+    /// Makes a player of the starting XI vice captain.
+    ///
+    /// The former vice captain becomes captain if the player was captain.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `FplError` if the player is not in the squad or is on the bench.
+    pub fn set_vice_captain(&mut self, player_id: i64) -> Result<(), FplError> {
+        let index = self.starting_pick_index(player_id)?;
+        if self.picks[index].is_captain {
+            let vice_captain = self.picks.iter().find(|pick| pick.is_vice_captain);
+            if let Some(vice_captain) = vice_captain {
+                return self.set_captain(vice_captain.element);
+            }
+        }
+        for pick in self.picks.iter_mut() {
+            pick.is_vice_captain = pick.element == player_id;
+        }
+        Ok(())
+    }
+
+    /// Returns whether the bench boost chip is played in the next gameweek, so that the bench scores too.
+    pub fn is_bench_boost_active(&self) -> bool {
+        self.chips
+            .iter()
+            .any(|chip| chip.name == Chip::BenchBoost.name() && chip.status_for_entry == "active")
+    }
+
+    /// Swaps the positions of two players of the squad, such as a starting player and a substitute.
+    ///
+    /// `element_types` maps the identifier of every player of the squad to its `element_type`,
+    /// which is needed to check the formation of the starting XI. The players moved to the bench
+    /// get the multiplier 0, or 1 if the bench boost chip is played.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `FplError` if a player is not in the squad, if the captain or
+    /// vice captain would be moved to the bench, or if the starting XI would not have exactly
+    /// one goalkeeper, at least three defenders and at least one forward.
+    pub fn substitute(
+        &mut self,
+        player_out: i64,
+        player_in: i64,
+        element_types: &HashMap<i64, i64>,
+    ) -> Result<(), FplError> {
+        let out_index = self.pick_index(player_out)?;
+        let in_index = self.pick_index(player_in)?;
+        let mut picks = self.picks.clone();
+        let out_position = picks[out_index].position;
+        picks[out_index].position = picks[in_index].position;
+        picks[in_index].position = out_position;
+        let bench_boost = self.is_bench_boost_active();
+        for pick in picks.iter_mut() {
+            let starting = pick.position <= STARTING_PLAYERS;
+            if !starting && (pick.is_captain || pick.is_vice_captain) {
+                return Err(FplError::from(
+                    "The captain and vice captain cannot be moved to the bench",
+                ));
+            }
+            if !pick.is_captain {
+                pick.multiplier = i64::from(starting || bench_boost);
+            }
+        }
+        check_formation(&picks, element_types)?;
+        picks.sort_by_key(|pick| pick.position);
+        self.picks = picks;
+        Ok(())
+    }
+
+    fn pick_index(&self, player_id: i64) -> Result<usize, FplError> {
+        self.picks
+            .iter()
+            .position(|pick| pick.element == player_id)
+            .ok_or_else(|| FplError::Other(format!("Player {} is not in the squad", player_id)))
+    }
+
+    fn starting_pick_index(&self, player_id: i64) -> Result<usize, FplError> {
+        let index = self.pick_index(player_id)?;
+        match self.picks[index].position <= STARTING_PLAYERS {
+            true => Ok(index),
+            false => Err(FplError::Other(format!(
+                "Player {} is on the bench",
+                player_id
+            ))),
+        }
+    }
+}
+
+/// Checks that the starting XI has exactly one goalkeeper, at least three defenders and at least one forward.
+fn check_formation(
+    picks: &[MyTeamPick],
+    element_types: &HashMap<i64, i64>,
+) -> Result<(), FplError> {
+    let mut counts: HashMap<Position, usize> = HashMap::new();
+    for pick in picks
+        .iter()
+        .filter(|pick| pick.position <= STARTING_PLAYERS)
+    {
+        let position = element_types
+            .get(&pick.element)
+            .and_then(|element_type| Position::from_element_type(*element_type))
+            .ok_or_else(|| {
+                FplError::Other(format!("Unknown position for player {}", pick.element))
+            })?;
+        *counts.entry(position).or_default() += 1;
+    }
+    let count = |position| counts.get(&position).copied().unwrap_or(0);
+    if count(Position::Goalkeeper) != 1
+        || count(Position::Defender) < 3
+        || count(Position::Forward) < 1
+    {
+        return Err(FplError::from(
+            "The starting XI needs one goalkeeper, at least three defenders and at least one forward",
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChipStatus {
+    pub name: String,
+    pub status_for_entry: String,
+    pub played_by_entry: Vec<i64>,
+    pub number: i64,
+    pub start_event: i64,
+    pub stop_event: i64,
+    pub chip_type: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferState {
+    pub cost: i64,
+    pub status: String,
+    pub limit: Option<i64>,
+    pub made: i64,
+    pub bank: i64,
+    pub value: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 4-4-2 starting XI (players 1 to 11) with a goalkeeper, a defender, a midfielder
+    /// and a forward on the bench (players 12 to 15), player 11 being captain and 10 vice captain.
+    fn squad() -> (MyTeam, HashMap<i64, i64>) {
+        let element_types = [1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 1, 2, 3, 4];
+        let picks = (1..=15)
+            .map(|element| MyTeamPick {
+                element,
+                position: element,
+                multiplier: match element {
+                    11 => 2,
+                    1..=10 => 1,
+                    _ => 0,
+                },
+                is_captain: element == 11,
+                is_vice_captain: element == 10,
+                ..Default::default()
+            })
+            .collect();
+        let my_team = MyTeam {
+            picks,
+            ..Default::default()
+        };
+        let element_types = (1..=15).zip(element_types).collect();
+        (my_team, element_types)
+    }
+
+    #[test]
+    fn test_set_captain() {
+        let (mut my_team, _) = squad();
+        my_team.set_captain(10).unwrap();
+        assert!(my_team.picks[9].is_captain);
+        assert_eq!(my_team.picks[9].multiplier, 2);
+        assert!(my_team.picks[10].is_vice_captain);
+        assert_eq!(my_team.picks[10].multiplier, 1);
+        assert!(my_team.set_captain(12).is_err());
+    }
+
+    #[test]
+    fn test_substitute() {
+        let (mut my_team, element_types) = squad();
+        my_team.substitute(6, 15, &element_types).unwrap();
+        let forward = my_team
+            .picks
+            .iter()
+            .find(|pick| pick.element == 15)
+            .unwrap();
+        assert_eq!((forward.position, forward.multiplier), (6, 1));
+        let midfielder = my_team.picks.iter().find(|pick| pick.element == 6).unwrap();
+        assert_eq!((midfielder.position, midfielder.multiplier), (15, 0));
+
+        // A 3-5-2 cannot lose another defender
+        let (mut my_team, element_types) = squad();
+        my_team.substitute(2, 14, &element_types).unwrap();
+        assert!(my_team.substitute(3, 15, &element_types).is_err());
+        assert!(my_team.substitute(1, 13, &element_types).is_err());
+        assert!(my_team.substitute(11, 15, &element_types).is_err());
+        assert!(my_team.substitute(1, 12, &element_types).is_ok());
+    }
+
+    #[test]
+    fn test_substitute_with_bench_boost() {
+        let (mut my_team, element_types) = squad();
+        my_team.chips = vec![ChipStatus {
+            name: String::from("bboost"),
+            status_for_entry: String::from("active"),
+            ..Default::default()
+        }];
+        assert!(my_team.is_bench_boost_active());
+        my_team.substitute(6, 15, &element_types).unwrap();
+        let midfielder = my_team.picks.iter().find(|pick| pick.element == 6).unwrap();
+        assert_eq!((midfielder.position, midfielder.multiplier), (15, 1));
+
+        my_team.chips[0].status_for_entry = String::from("available");
+        assert!(!my_team.is_bench_boost_active());
+        my_team.substitute(15, 6, &element_types).unwrap();
+        my_team.substitute(6, 14, &element_types).unwrap();
+        let midfielder = my_team.picks.iter().find(|pick| pick.element == 6).unwrap();
+        assert_eq!((midfielder.position, midfielder.multiplier), (14, 0));
+    }
+
+    #[test]
+    fn test_deserialize_chip_status() {
+        let json = r#"{
+            "status_for_entry": "played",
+            "played_by_entry": [7],
+            "name": "wildcard",
+            "number": 1,
+            "start_event": 2,
+            "stop_event": 19,
+            "chip_type": "transfer"
+        }"#;
+        let chip: ChipStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(chip.name, "wildcard");
+        assert_eq!(chip.status_for_entry, "played");
+        assert_eq!(chip.played_by_entry, vec![7]);
+    }
+}
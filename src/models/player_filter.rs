@@ -0,0 +1,111 @@
+use crate::models::bootstrap_static::{Player, Position};
+
+/// Criteria players are filtered by, every criterion being optional.
+///
+/// # Examples
+///
+/// ```rust
+/// use fpl_rs::models::bootstrap_static::Position;
+/// use fpl_rs::models::player_filter::PlayerFilter;
+///
+/// fn main() {
+///     // Midfielders costing at most £6.0m who played at least 90 minutes
+///     let filter = PlayerFilter::new()
+///         .position(Position::Midfielder)
+///         .max_price(6.0)
+///         .min_minutes(90);
+///
+///     // Filter players with `Fpl::get_players_filtered`
+///     // ...
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct PlayerFilter {
+    pub position: Option<Position>,
+    pub min_price: Option<f64>,
+    pub max_price: Option<f64>,
+    pub team: Option<i64>,
+    pub min_minutes: Option<i64>,
+}
+
+impl PlayerFilter {
+    /// Creates a new filter, which every player matches.
+    pub fn new() -> PlayerFilter {
+        PlayerFilter::default()
+    }
+
+    /// Keeps the players playing in `position`.
+    pub fn position(mut self, position: Position) -> PlayerFilter {
+        self.position = Some(position);
+        self
+    }
+
+    /// Keeps the players costing at least `min_price` millions.
+    pub fn min_price(mut self, min_price: f64) -> PlayerFilter {
+        self.min_price = Some(min_price);
+        self
+    }
+
+    /// Keeps the players costing at most `max_price` millions.
+    pub fn max_price(mut self, max_price: f64) -> PlayerFilter {
+        self.max_price = Some(max_price);
+        self
+    }
+
+    /// Keeps the players of the team with the identifier `team`.
+    pub fn team(mut self, team: i64) -> PlayerFilter {
+        self.team = Some(team);
+        self
+    }
+
+    /// Keeps the players who played at least `min_minutes` minutes this season.
+    pub fn min_minutes(mut self, min_minutes: i64) -> PlayerFilter {
+        self.min_minutes = Some(min_minutes);
+        self
+    }
+
+    /// Returns whether the player matches every criterion of the filter.
+    // `Option::is_none_or` would need Rust 1.82.
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn matches(&self, player: &Player) -> bool {
+        self.position
+            .map_or(true, |position| player.position() == Some(position))
+            && self
+                .min_price
+                .map_or(true, |min_price| player.price() >= min_price)
+            && self
+                .max_price
+                .map_or(true, |max_price| player.price() <= max_price)
+            && self.team.map_or(true, |team| player.team == team)
+            && self
+                .min_minutes
+                .map_or(true, |min_minutes| player.minutes >= min_minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_player_filter_matches() {
+        let player = Player {
+            element_type: 3,
+            now_cost: 55,
+            team: 1,
+            minutes: 90,
+            ..Default::default()
+        };
+        assert!(PlayerFilter::new().matches(&player));
+        let filter = PlayerFilter::new()
+            .position(Position::Midfielder)
+            .min_price(5.5)
+            .max_price(6.0)
+            .team(1)
+            .min_minutes(90);
+        assert!(filter.matches(&player));
+        assert!(!filter.clone().max_price(5.0).matches(&player));
+        assert!(!filter.clone().position(Position::Forward).matches(&player));
+        assert!(!filter.min_minutes(91).matches(&player));
+    }
+}
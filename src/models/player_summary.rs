@@ -0,0 +1,103 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::fpl_error::FplError;
+
+/// The summaries of several players, each along with the id of the player it was requested for.
+pub type PlayerSummaries = Vec<(i64, Result<PlayerSummary, FplError>)>;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSummary {
+    pub fixtures: Vec<PlayerFixture>,
+    pub history: Vec<PlayerHistory>,
+    pub history_past: Vec<PlayerHistoryPast>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerFixture {
+    pub id: i64,
+    pub code: i64,
+    pub team_h: i64,
+    pub team_h_score: Option<i64>,
+    pub team_a: i64,
+    pub team_a_score: Option<i64>,
+    pub event: Option<i64>,
+    pub finished: bool,
+    pub minutes: i64,
+    pub provisional_start_time: bool,
+    pub kickoff_time: Option<String>,
+    pub event_name: Option<String>,
+    pub is_home: bool,
+    pub difficulty: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerHistory {
+    pub element: i64,
+    pub fixture: i64,
+    pub opponent_team: i64,
+    pub total_points: i64,
+    pub was_home: bool,
+    pub kickoff_time: String,
+    pub team_h_score: Option<i64>,
+    pub team_a_score: Option<i64>,
+    pub round: i64,
+    pub minutes: i64,
+    pub goals_scored: i64,
+    pub assists: i64,
+    pub clean_sheets: i64,
+    pub goals_conceded: i64,
+    pub own_goals: i64,
+    pub penalties_saved: i64,
+    pub penalties_missed: i64,
+    pub yellow_cards: i64,
+    pub red_cards: i64,
+    pub saves: i64,
+    pub bonus: i64,
+    pub bps: i64,
+    pub influence: String,
+    pub creativity: String,
+    pub threat: String,
+    pub ict_index: String,
+    pub starts: i64,
+    pub expected_goals: String,
+    pub expected_assists: String,
+    pub expected_goal_involvements: String,
+    pub expected_goals_conceded: String,
+    pub value: i64,
+    pub transfers_balance: i64,
+    pub selected: i64,
+    pub transfers_in: i64,
+    pub transfers_out: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerHistoryPast {
+    pub season_name: String,
+    pub element_code: i64,
+    pub start_cost: i64,
+    pub end_cost: i64,
+    pub total_points: i64,
+    pub minutes: i64,
+    pub goals_scored: i64,
+    pub assists: i64,
+    pub clean_sheets: i64,
+    pub goals_conceded: i64,
+    pub own_goals: i64,
+    pub penalties_saved: i64,
+    pub penalties_missed: i64,
+    pub yellow_cards: i64,
+    pub red_cards: i64,
+    pub saves: i64,
+    pub bonus: i64,
+    pub bps: i64,
+    pub influence: String,
+    pub creativity: String,
+    pub threat: String,
+    pub ict_index: String,
+    pub starts: i64,
+    pub expected_goals: String,
+    pub expected_assists: String,
+    pub expected_goal_involvements: String,
+    pub expected_goals_conceded: String,
+}
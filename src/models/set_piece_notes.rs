@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetPieceNotes {
+    pub last_updated: String,
+    pub teams: Vec<TeamSetPieceNotes>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamSetPieceNotes {
+    pub id: i64,
+    pub notes: Vec<Note>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Note {
+    pub external_link: bool,
+    pub info_message: String,
+    pub source_link: String,
+}
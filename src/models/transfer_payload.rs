@@ -0,0 +1,163 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::fpl_error::FplError;
+
+/// The chips which can be played along with a set of transfers.
+const TRANSFER_CHIPS: [&str; 2] = ["wildcard", "freehit"];
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferPayload {
+    pub entry: i64,
+    pub event: i64,
+    pub transfers: Vec<TransferRequest>,
+    pub chip: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferRequest {
+    pub element_in: i64,
+    pub element_out: i64,
+    pub purchase_price: i64,
+    pub selling_price: i64,
+}
+
+/// Builder for the transfers made by an entry for a gameweek.
+///
+/// # Examples
+///
+/// ```rust
+/// use fpl_rs::models::transfer_payload::TransferPayloadBuilder;
+///
+/// fn main() {
+///     // Swap player 1 for player 2 while playing the wildcard
+///     let payload = TransferPayloadBuilder::new(12345, 10)
+///         .transfer(2, 1, 55, 50)
+///         .chip("wildcard")
+///         .build();
+///
+///     // Post the payload with `Fpl::make_transfers`
+///     // ...
+/// }
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct TransferPayloadBuilder {
+    entry: i64,
+    event: i64,
+    transfers: Vec<TransferRequest>,
+    chip: Option<String>,
+}
+
+impl TransferPayloadBuilder {
+    /// Creates a new builder for the transfers of an entry for a gameweek.
+    pub fn new(entry: i64, event: i64) -> TransferPayloadBuilder {
+        TransferPayloadBuilder {
+            entry,
+            event,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a transfer of `element_out`, sold at `selling_price`, for `element_in`, bought at `purchase_price`.
+    ///
+    /// Prices are in tenths of a million, like `Player::now_cost`.
+    pub fn transfer(
+        mut self,
+        element_in: i64,
+        element_out: i64,
+        purchase_price: i64,
+        selling_price: i64,
+    ) -> TransferPayloadBuilder {
+        self.transfers.push(TransferRequest {
+            element_in,
+            element_out,
+            purchase_price,
+            selling_price,
+        });
+        self
+    }
+
+    /// Sets the chip played along with the transfers, either `wildcard` or `freehit`.
+    pub fn chip(mut self, chip: &str) -> TransferPayloadBuilder {
+        self.chip = Some(String::from(chip));
+        self
+    }
+
+    /// Creates the payload, after checking it locally.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an `FplError` if the entry or the gameweek is not valid,
+    /// if no transfers were added, if a player is transferred more than once,
+    /// or if the chip cannot be played along with transfers.
+    pub fn build(self) -> Result<TransferPayload, FplError> {
+        if self.entry <= 0 {
+            return Err(FplError::from("The entry must be a positive identifier"));
+        }
+        if !(1..=38).contains(&self.event) {
+            return Err(FplError::from("The gameweek must be between 1 and 38"));
+        }
+        if self.transfers.is_empty() {
+            return Err(FplError::from("At least one transfer is required"));
+        }
+        let mut elements: Vec<i64> = self
+            .transfers
+            .iter()
+            .flat_map(|transfer| [transfer.element_in, transfer.element_out])
+            .collect();
+        elements.sort_unstable();
+        elements.dedup();
+        if elements.len() != self.transfers.len() * 2 {
+            return Err(FplError::from("A player can only be transferred once"));
+        }
+        if let Some(chip) = &self.chip {
+            if !TRANSFER_CHIPS.contains(&chip.as_str()) {
+                return Err(FplError::Other(format!(
+                    "The {} chip cannot be played along with transfers",
+                    chip
+                )));
+            }
+        }
+        Ok(TransferPayload {
+            entry: self.entry,
+            event: self.event,
+            transfers: self.transfers,
+            chip: self.chip,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_transfer_payload() {
+        let payload = TransferPayloadBuilder::new(12345, 10)
+            .transfer(2, 1, 55, 50)
+            .chip("freehit")
+            .build()
+            .unwrap();
+        assert_eq!(payload.transfers.len(), 1);
+        assert_eq!(payload.chip, Some(String::from("freehit")));
+    }
+
+    #[test]
+    fn test_build_invalid_transfer_payload() {
+        assert!(TransferPayloadBuilder::new(12345, 10).build().is_err());
+        assert!(TransferPayloadBuilder::new(12345, 39)
+            .transfer(2, 1, 55, 50)
+            .build()
+            .is_err());
+        assert!(TransferPayloadBuilder::new(12345, 10)
+            .transfer(2, 1, 55, 50)
+            .transfer(3, 1, 55, 50)
+            .build()
+            .is_err());
+        assert!(TransferPayloadBuilder::new(12345, 10)
+            .transfer(2, 1, 55, 50)
+            .chip("bboost")
+            .build()
+            .is_err());
+    }
+}
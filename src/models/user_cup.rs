@@ -0,0 +1,107 @@
+use super::league::Status;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserCup {
+    #[serde(rename = "cup_matches")]
+    pub matches: Vec<CupMatch>,
+    pub cup_status: Status,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CupMatch {
+    pub id: i64,
+    pub entry_1_entry: i64,
+    pub entry_1_name: String,
+    pub entry_1_player_name: String,
+    pub entry_1_points: i64,
+    pub entry_1_win: i64,
+    pub entry_1_draw: i64,
+    pub entry_1_loss: i64,
+    pub entry_1_total: i64,
+    pub entry_2_entry: Option<i64>,
+    pub entry_2_name: Option<String>,
+    pub entry_2_player_name: Option<String>,
+    pub entry_2_points: i64,
+    pub entry_2_win: i64,
+    pub entry_2_draw: i64,
+    pub entry_2_loss: i64,
+    pub entry_2_total: i64,
+    pub is_knockout: bool,
+    pub league: i64,
+    pub winner: Option<i64>,
+    pub seed_value: Value,
+    pub event: i64,
+    pub tiebreak: Value,
+    pub is_bye: bool,
+    pub knockout_name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER_CUP: &str = r#"{
+        "cup_matches": [
+            {
+                "id": 4312077,
+                "entry_1_entry": 5489342,
+                "entry_1_name": "Expected Toulouse",
+                "entry_1_player_name": "Ben Wilson",
+                "entry_1_points": 63,
+                "entry_1_win": 0,
+                "entry_1_draw": 0,
+                "entry_1_loss": 0,
+                "entry_1_total": 0,
+                "entry_2_entry": 2801155,
+                "entry_2_name": "Haaland Globetrotters",
+                "entry_2_player_name": "Sam Jones",
+                "entry_2_points": 48,
+                "entry_2_win": 0,
+                "entry_2_draw": 0,
+                "entry_2_loss": 0,
+                "entry_2_total": 0,
+                "is_knockout": true,
+                "league": 1856,
+                "winner": 5489342,
+                "seed_value": null,
+                "event": 17,
+                "tiebreak": null,
+                "is_bye": false,
+                "knockout_name": "Round of 4194304"
+            }
+        ],
+        "cup_status": {
+            "qualification_event": 16,
+            "qualification_numbers": 4021823,
+            "qualification_rank": 1205034,
+            "qualification_state": "QUALIFIED"
+        }
+    }"#;
+
+    const USER_CUP_NOT_STARTED: &str = r#"{
+        "cup_matches": [],
+        "cup_status": {
+            "qualification_event": null,
+            "qualification_numbers": null,
+            "qualification_rank": null,
+            "qualification_state": null
+        }
+    }"#;
+
+    #[test]
+    fn test_deserialize_user_cup() {
+        let user_cup: UserCup = serde_json::from_str(USER_CUP).unwrap();
+        assert!(user_cup.matches.len() == 1);
+        assert!(user_cup.matches[0].entry_1_entry == 5489342);
+        assert!(user_cup.matches[0].event == 17);
+    }
+
+    #[test]
+    fn test_deserialize_user_cup_not_started() {
+        let user_cup: UserCup = serde_json::from_str(USER_CUP_NOT_STARTED).unwrap();
+        assert!(user_cup.matches.is_empty());
+    }
+}
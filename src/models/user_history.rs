@@ -0,0 +1,29 @@
+use super::user_picks::EntryHistory;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A single gameweek of a user's season, as found in `current`.
+///
+/// The payload is identical to the `entry_history` of the picks endpoint.
+pub type GameweekEntry = EntryHistory;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserHistory {
+    pub current: Vec<GameweekEntry>,
+    pub past: Vec<PastSeason>,
+    pub chips: Vec<ChipUse>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PastSeason {
+    pub season_name: String,
+    pub total_points: i64,
+    pub rank: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChipUse {
+    pub name: String,
+    pub time: String,
+    pub event: i64,
+}
@@ -1,15 +1,85 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 use serde::Serialize;
-use serde_json::Value;
+
+use crate::models::bootstrap_static::Position;
+use crate::models::gameweek::Gameweek;
+
+/// How many picks of a squad start, the others being on the bench.
+const STARTING_PLAYERS: i64 = 11;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserPicks {
-    pub active_chip: Value,
-    pub automatic_subs: Vec<Value>,
+    pub active_chip: Option<Chip>,
+    pub automatic_subs: Vec<AutomaticSub>,
     pub entry_history: EntryHistory,
     pub picks: Vec<Pick>,
 }
 
+/// A chip, which a user can play once per half of the season to boost a gameweek.
+///
+/// It is (de)serialized from and to the name the FPL API gives it, such as `"bboost"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Chip {
+    /// Unlimited free transfers, kept after the gameweek.
+    Wildcard,
+    /// Unlimited free transfers, reverted after the gameweek.
+    FreeHit,
+    /// The points of the bench count too.
+    BenchBoost,
+    /// The captain scores triple points.
+    TripleCaptain,
+    /// A chip this crate does not know about yet, with its name.
+    Other(String),
+}
+
+impl Chip {
+    /// Returns the name the FPL API gives the chip, such as `"bboost"` for the bench boost.
+    pub fn name(&self) -> &str {
+        match self {
+            Chip::Wildcard => "wildcard",
+            Chip::FreeHit => "freehit",
+            Chip::BenchBoost => "bboost",
+            Chip::TripleCaptain => "3xc",
+            Chip::Other(name) => name,
+        }
+    }
+
+    /// Returns the chip the FPL API gives the name `name`.
+    pub fn from_name(name: &str) -> Chip {
+        match name {
+            "wildcard" => Chip::Wildcard,
+            "freehit" => Chip::FreeHit,
+            "bboost" => Chip::BenchBoost,
+            "3xc" => Chip::TripleCaptain,
+            other => Chip::Other(String::from(other)),
+        }
+    }
+}
+
+impl Serialize for Chip {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Chip {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Chip, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(Chip::from_name(&name))
+    }
+}
+
+/// A substitution made automatically because a starting player did not play.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutomaticSub {
+    pub entry: i64,
+    pub element_in: i64,
+    pub element_out: i64,
+    pub event: i64,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EntryHistory {
     pub event: i64,
@@ -34,3 +104,400 @@ pub struct Pick {
     pub is_vice_captain: bool,
 }
 
+impl UserPicks {
+    /// Returns the pick of the captain, if any.
+    pub fn captain(&self) -> Option<&Pick> {
+        self.picks.iter().find(|pick| pick.is_captain)
+    }
+
+    /// Returns the pick of the vice captain, if any.
+    pub fn vice_captain(&self) -> Option<&Pick> {
+        self.picks.iter().find(|pick| pick.is_vice_captain)
+    }
+
+    /// Returns the picks of the starting XI, in positions 1 to 11.
+    pub fn starting_eleven(&self) -> Vec<&Pick> {
+        self.picks
+            .iter()
+            .filter(|pick| pick.position <= STARTING_PLAYERS)
+            .collect()
+    }
+
+    /// Returns the picks of the bench, in positions 12 to 15.
+    pub fn bench(&self) -> Vec<&Pick> {
+        self.picks
+            .iter()
+            .filter(|pick| pick.position > STARTING_PLAYERS)
+            .collect()
+    }
+
+    /// Returns the picks once the automatic substitutions the FPL API recorded in `automatic_subs`
+    /// are made, ordered by position.
+    ///
+    /// This only replays the substitutions the FPL API made, which it lists once the gameweek has
+    /// been processed, so `automatic_subs` is empty while the gameweek is live. To make them from
+    /// the live data instead, see
+    /// [`with_auto_subs_from_live`](struct.UserPicks.html#method.with_auto_subs_from_live).
+    ///
+    /// Every bench player coming on takes the position of the starting player they replace, who
+    /// moves to the bench. When the captain is substituted, the vice captain, if still starting,
+    /// gets the captain multiplier. Substitutions involving players missing from the picks are ignored.
+    pub fn with_auto_subs_applied(&self) -> Vec<Pick> {
+        let mut picks = self.picks.clone();
+        let mut captain_multiplier = None;
+        for sub in &self.automatic_subs {
+            let out_index = picks
+                .iter()
+                .position(|pick| pick.element == sub.element_out);
+            let in_index = picks.iter().position(|pick| pick.element == sub.element_in);
+            let (Some(out_index), Some(in_index)) = (out_index, in_index) else {
+                continue;
+            };
+            if picks[out_index].is_captain {
+                captain_multiplier = Some(picks[out_index].multiplier.max(2));
+            }
+            let out_position = picks[out_index].position;
+            picks[out_index].position = picks[in_index].position;
+            picks[in_index].position = out_position;
+            picks[out_index].multiplier = 0;
+            picks[in_index].multiplier = 1;
+        }
+        if let Some(captain_multiplier) = captain_multiplier {
+            if let Some(vice_captain) = picks
+                .iter_mut()
+                .find(|pick| pick.is_vice_captain && pick.position <= STARTING_PLAYERS)
+            {
+                vice_captain.multiplier = captain_multiplier;
+            }
+        }
+        picks.sort_by_key(|pick| pick.position);
+        picks
+    }
+
+    /// Returns the picks once the automatic substitutions are made from the minutes played in the
+    /// live data of the gameweek, ordered by position, such as to compute live points before the
+    /// FPL API makes them.
+    ///
+    /// `positions` gives the position of the players by id, such as from the static data.
+    /// Every starting player who has not played is replaced by the first bench player, in bench
+    /// order, who has played and keeps a valid formation: a goalkeeper only replaces a goalkeeper,
+    /// and the starting XI keeps at least 3 defenders, 2 midfielders and 1 forward. The substitute
+    /// takes the position and the multiplier 1, and the player replaced moves to the bench with the
+    /// multiplier 0. When the captain has not played, the vice captain, if starting and they have
+    /// played, gets the captain multiplier. No substitutions are made when the bench boost chip is played.
+    ///
+    /// A player missing from the live data or who has played 0 minutes counts as not having played,
+    /// so the players whose match has not started yet are substituted too.
+    pub fn with_auto_subs_from_live(
+        &self,
+        live: &Gameweek,
+        positions: &HashMap<i64, Position>,
+    ) -> Vec<Pick> {
+        let minutes: HashMap<i64, i64> = live
+            .elements
+            .iter()
+            .map(|element| (element.id, element.stats.minutes))
+            .collect();
+        let played = |pick: &Pick| {
+            minutes
+                .get(&pick.element)
+                .is_some_and(|&minutes| minutes > 0)
+        };
+        let position = |pick: &Pick| positions.get(&pick.element).copied();
+        let mut picks = self.picks.clone();
+        picks.sort_by_key(|pick| pick.position);
+        if self.active_chip != Some(Chip::BenchBoost) {
+            let mut bench: Vec<usize> = (0..picks.len())
+                .filter(|&index| picks[index].position > STARTING_PLAYERS && played(&picks[index]))
+                .collect();
+            for out_index in 0..picks.len() {
+                if picks[out_index].position > STARTING_PLAYERS || played(&picks[out_index]) {
+                    continue;
+                }
+                let out_position = position(&picks[out_index]);
+                let Some(bench_index) = bench.iter().position(|&in_index| {
+                    let in_position = position(&picks[in_index]);
+                    if out_position == Some(Position::Goalkeeper)
+                        || in_position == Some(Position::Goalkeeper)
+                    {
+                        return in_position == out_position;
+                    }
+                    let starting = picks
+                        .iter()
+                        .filter(|pick| {
+                            pick.position <= STARTING_PLAYERS && position(pick) == out_position
+                        })
+                        .count();
+                    in_position == out_position || starting > min_starting(out_position)
+                }) else {
+                    continue;
+                };
+                let in_index = bench.remove(bench_index);
+                let out_position = picks[out_index].position;
+                picks[out_index].position = picks[in_index].position;
+                picks[in_index].position = out_position;
+                picks[out_index].multiplier = 0;
+                picks[in_index].multiplier = 1;
+            }
+        }
+        if let Some(captain) = self
+            .picks
+            .iter()
+            .find(|pick| pick.is_captain && !played(pick))
+        {
+            let captain_multiplier = captain.multiplier.max(2);
+            if let Some(vice_captain) = picks.iter_mut().find(|pick| {
+                pick.is_vice_captain && pick.position <= STARTING_PLAYERS && played(pick)
+            }) {
+                vice_captain.multiplier = captain_multiplier;
+                if let Some(captain) = picks
+                    .iter_mut()
+                    .find(|pick| pick.is_captain && pick.position <= STARTING_PLAYERS)
+                {
+                    captain.multiplier = 1;
+                }
+            }
+        }
+        picks.sort_by_key(|pick| pick.position);
+        picks
+    }
+
+    /// Returns the points scored by the picks in the live data of the gameweek.
+    ///
+    /// The points of every player are multiplied by the multiplier of their pick, which is 2 for
+    /// the captain, 3 when the triple captain chip is played, and 0 for the bench unless the bench
+    /// boost chip is played. Players missing from the live data score no points.
+    ///
+    /// Automatic substitutions are not applied: a bench player coming on for a starting player
+    /// who did not play is not counted, unless the picks come from
+    /// [`with_auto_subs_applied`](struct.UserPicks.html#method.with_auto_subs_applied) or
+    /// [`with_auto_subs_from_live`](struct.UserPicks.html#method.with_auto_subs_from_live).
+    /// Transfer hits are not deducted either.
+    pub fn total_points(&self, live: &Gameweek) -> i64 {
+        let points: HashMap<i64, i64> = live
+            .elements
+            .iter()
+            .map(|element| (element.id, element.stats.total_points))
+            .collect();
+        self.picks
+            .iter()
+            .map(|pick| points.get(&pick.element).copied().unwrap_or(0) * pick.multiplier)
+            .sum()
+    }
+}
+
+/// Returns how many players of `position` the starting XI needs at least.
+fn min_starting(position: Option<Position>) -> usize {
+    match position {
+        Some(Position::Goalkeeper) | Some(Position::Forward) => 1,
+        Some(Position::Defender) => 3,
+        Some(Position::Midfielder) => 2,
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::gameweek::{Element, Stats};
+
+    fn user_picks() -> UserPicks {
+        let picks = (1..=15)
+            .map(|position| Pick {
+                element: position * 10,
+                position,
+                multiplier: match position {
+                    1 => 2,
+                    12.. => 0,
+                    _ => 1,
+                },
+                is_captain: position == 1,
+                is_vice_captain: position == 2,
+            })
+            .collect();
+        UserPicks {
+            picks,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_captain() {
+        let user_picks = user_picks();
+        assert_eq!(user_picks.captain().map(|pick| pick.element), Some(10));
+        assert_eq!(user_picks.vice_captain().map(|pick| pick.element), Some(20));
+        assert!(UserPicks::default().captain().is_none());
+    }
+
+    #[test]
+    fn test_starting_eleven_and_bench() {
+        let user_picks = user_picks();
+        let starting_eleven = user_picks.starting_eleven();
+        let bench = user_picks.bench();
+        assert_eq!(starting_eleven.len(), 11);
+        assert_eq!(bench.len(), 4);
+        assert!(bench.iter().all(|pick| pick.position > 11));
+    }
+
+    #[test]
+    fn test_total_points() {
+        let mut user_picks = user_picks();
+        let elements = (1..=15)
+            .map(|position| Element {
+                id: position * 10,
+                stats: Stats {
+                    total_points: position,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .collect();
+        let live = Gameweek { elements };
+        // The captain scores 1 point twice, the other starting players 2 to 11 points.
+        assert_eq!(user_picks.total_points(&live), 2 + (2..=11).sum::<i64>());
+
+        user_picks.picks[0].multiplier = 3;
+        assert_eq!(user_picks.total_points(&live), 3 + (2..=11).sum::<i64>());
+        assert_eq!(user_picks.total_points(&Gameweek::default()), 0);
+    }
+
+    #[test]
+    fn test_deserialize_automatic_subs() {
+        let json = r#"[{"entry": 12345, "element_in": 120, "element_out": 30, "event": 14}]"#;
+        let automatic_subs: Vec<AutomaticSub> = serde_json::from_str(json).unwrap();
+        assert_eq!(automatic_subs[0].element_in, 120);
+        assert_eq!(automatic_subs[0].element_out, 30);
+    }
+
+    #[test]
+    fn test_with_auto_subs_applied() {
+        let mut user_picks = user_picks();
+        user_picks.automatic_subs = vec![
+            AutomaticSub {
+                element_in: 130,
+                element_out: 10,
+                ..Default::default()
+            },
+            AutomaticSub {
+                element_in: 120,
+                element_out: 30,
+                ..Default::default()
+            },
+            AutomaticSub {
+                element_in: 999,
+                element_out: 40,
+                ..Default::default()
+            },
+        ];
+        let picks = user_picks.with_auto_subs_applied();
+        let starting: Vec<i64> = picks[..11].iter().map(|pick| pick.element).collect();
+        assert_eq!(
+            starting,
+            vec![130, 20, 120, 40, 50, 60, 70, 80, 90, 100, 110]
+        );
+        let bench: Vec<i64> = picks[11..].iter().map(|pick| pick.element).collect();
+        assert_eq!(bench, vec![30, 10, 140, 150]);
+        // The captain came off, so the vice captain gets the captain multiplier.
+        assert_eq!(picks[1].multiplier, 2);
+        assert_eq!(picks[0].multiplier, 1);
+        assert!(picks[11..].iter().all(|pick| pick.multiplier == 0));
+    }
+
+    #[test]
+    fn test_with_auto_subs_from_live() {
+        // A goalkeeper, 3 defenders, 5 midfielders and 2 forwards, then a goalkeeper, a defender,
+        // a midfielder and a forward on the bench.
+        let positions: HashMap<i64, Position> = (1..=15)
+            .map(|position| {
+                let player_position = match position {
+                    1 | 12 => Position::Goalkeeper,
+                    2..=4 | 13 => Position::Defender,
+                    5..=9 | 14 => Position::Midfielder,
+                    _ => Position::Forward,
+                };
+                (position * 10, player_position)
+            })
+            .collect();
+        let mut user_picks = user_picks();
+        for pick in &mut user_picks.picks {
+            pick.is_captain = pick.element == 50;
+            pick.is_vice_captain = pick.element == 60;
+            pick.multiplier = match pick.position {
+                5 => 2,
+                12.. => 0,
+                _ => 1,
+            };
+        }
+        // The goalkeeper, a defender, the captain, a forward and the bench defender did not play.
+        let elements = (1..=15)
+            .map(|position| position * 10)
+            .filter(|id| ![10, 20, 50, 100, 130].contains(id))
+            .map(|id| Element {
+                id,
+                stats: Stats {
+                    minutes: 90,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .collect();
+        let live = Gameweek { elements };
+
+        let picks = user_picks.with_auto_subs_from_live(&live, &positions);
+        let elements: Vec<i64> = picks.iter().map(|pick| pick.element).collect();
+        // The defender stays, since no bench defender played and the XI needs 3 defenders.
+        assert_eq!(
+            elements,
+            vec![120, 20, 30, 40, 140, 60, 70, 80, 90, 150, 110, 10, 130, 50, 100]
+        );
+        let multiplier = |element| {
+            picks
+                .iter()
+                .find(|pick| pick.element == element)
+                .unwrap()
+                .multiplier
+        };
+        assert_eq!(multiplier(60), 2);
+        assert_eq!(multiplier(140), 1);
+        assert_eq!(multiplier(50), 0);
+        assert_eq!(multiplier(10), 0);
+
+        user_picks.active_chip = Some(Chip::BenchBoost);
+        let picks = user_picks.with_auto_subs_from_live(&live, &positions);
+        assert!(picks
+            .iter()
+            .zip(1..)
+            .all(|(pick, position)| pick.position == position));
+        // The captain did not play either way, so the vice captain still gets the captain multiplier.
+        assert_eq!(picks[5].multiplier, 2);
+    }
+
+    #[test]
+    fn test_deserialize_active_chip() {
+        let user_picks: UserPicks = serde_json::from_value(serde_json::json!({
+            "active_chip": "3xc",
+            "automatic_subs": [],
+            "entry_history": EntryHistory::default(),
+            "picks": []
+        }))
+        .unwrap();
+        assert_eq!(user_picks.active_chip, Some(Chip::TripleCaptain));
+        let json = serde_json::to_value(&user_picks).unwrap();
+        assert_eq!(json["active_chip"], "3xc");
+
+        let chips: Vec<Option<Chip>> =
+            serde_json::from_str(r#"[null, "bboost", "freehit", "wildcard", "manager"]"#).unwrap();
+        assert_eq!(
+            chips,
+            vec![
+                None,
+                Some(Chip::BenchBoost),
+                Some(Chip::FreeHit),
+                Some(Chip::Wildcard),
+                Some(Chip::Other(String::from("manager")))
+            ]
+        );
+        // Unknown chips keep their name.
+        assert_eq!(serde_json::to_value(&chips).unwrap()[4], "manager");
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Token bucket limiting how many requests are made over a period, shared by the clones of `Fpl`.
+///
+/// The bucket holds up to `capacity` tokens and is refilled continuously. Taking a token when the
+/// bucket is empty reserves the next one to be refilled, so that waiting requests are let through
+/// in order, one per refill, instead of failing.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    /// How many requests can be made at once after a quiet period.
+    capacity: f64,
+    /// How many tokens are refilled per second.
+    refill_rate: f64,
+    /// The tokens in the bucket, negative when tokens are reserved, and when they were counted.
+    state: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter letting through `max_requests` requests every `per` period.
+    pub(crate) fn new(max_requests: u32, per: Duration) -> RateLimiter {
+        let capacity = f64::from(max_requests.max(1));
+        RateLimiter {
+            capacity,
+            refill_rate: capacity / per.as_secs_f64().max(f64::EPSILON),
+            state: Mutex::new((capacity, Instant::now())),
+        }
+    }
+
+    /// Asynchronously waits until a request can be made, taking a token.
+    pub(crate) async fn acquire(&self) {
+        let wait = {
+            let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+            let (tokens, counted_at) = *state;
+            let now = Instant::now();
+            let refilled = now.duration_since(counted_at).as_secs_f64() * self.refill_rate;
+            let tokens = (tokens + refilled).min(self.capacity) - 1.0;
+            *state = (tokens, now);
+            if tokens < 0.0 {
+                Duration::from_secs_f64(-tokens / self.refill_rate)
+            } else {
+                Duration::ZERO
+            }
+        };
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
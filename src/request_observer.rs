@@ -0,0 +1,59 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+/// Observer of the requests made by the `Fpl` API wrapper, such as for logging or metrics.
+///
+/// `before` is called right before a request is sent and `after` once it is answered or has failed,
+/// so every request made, including each attempt of a request made again, is reported by one pair of calls.
+/// Observers can't change the requests.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use fpl_rs::{fpl_error::FplError, request_observer::RequestObserver, Fpl};
+/// use reqwest::StatusCode;
+///
+/// struct Logger;
+///
+/// impl RequestObserver for Logger {
+///     fn before(&self, url: &str) {
+///         println!("GET {}", url);
+///     }
+///
+///     fn after(&self, url: &str, status: Option<StatusCode>, elapsed: Duration) {
+///         println!("{} answered with {:?} in {:?}", url, status, elapsed);
+///     }
+/// }
+///
+/// fn main() -> Result<(), FplError> {
+///     // Create a new Fpl instance which logs every request it makes
+///     let fpl = Fpl::builder().observer(Logger).build()?;
+///
+///     // Use the Fpl instance to make API requests
+///     // ...
+///     Ok(())
+/// }
+/// ```
+pub trait RequestObserver: Send + Sync {
+    /// Called right before a request is sent to `url`.
+    fn before(&self, _url: &str) {}
+
+    /// Called once the request to `url` is answered, with the status of the response,
+    /// or has failed without a response, with no status, after `elapsed` time.
+    fn after(&self, _url: &str, _status: Option<StatusCode>, _elapsed: Duration) {}
+}
+
+/// A shared `RequestObserver`, which the `Fpl` API wrapper and its builder can be formatted with.
+#[derive(Clone)]
+pub(crate) struct SharedObserver(pub(crate) Arc<dyn RequestObserver>);
+
+impl fmt::Debug for SharedObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RequestObserver")
+    }
+}
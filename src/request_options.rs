@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+/// Options overriding the configuration of the `Fpl` API wrapper for a single request.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use fpl_rs::request_options::RequestOptions;
+///
+/// fn main() {
+///     // Give up on the request after 5 seconds, whatever the timeout of the Fpl instance
+///     let options = RequestOptions::new().timeout(Duration::from_secs(5));
+///
+///     // Pass the options to a method such as `Fpl::get_live_gameweek_with_options`
+///     // ...
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct RequestOptions {
+    /// The total time allowed for the request, instead of the timeout of the `Fpl` instance.
+    ///
+    /// Ignored on WebAssembly, where the browser applies its own timeouts.
+    pub timeout: Option<Duration>,
+}
+
+impl RequestOptions {
+    /// Creates new options, which keep the configuration of the `Fpl` instance.
+    pub fn new() -> RequestOptions {
+        RequestOptions::default()
+    }
+
+    /// Sets the total time allowed for the request, from connecting until the response body is read.
+    pub fn timeout(mut self, timeout: Duration) -> RequestOptions {
+        self.timeout = Some(timeout);
+        self
+    }
+}
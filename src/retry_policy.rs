@@ -0,0 +1,65 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reqwest::{Response, StatusCode};
+
+/// The longest time waited before retrying a request, however many attempts have failed.
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// How requests failing transiently are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RetryPolicy {
+    /// How many times a request is retried after its first attempt.
+    pub(crate) max_retries: u32,
+    /// The time waited before the first retry, doubled for every following one.
+    pub(crate) base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Returns whether the outcome of an attempt is a transient failure worth retrying:
+    /// a network failure, a server error or too many requests.
+    pub(crate) fn is_retryable(result: &Result<Response, reqwest::Error>) -> bool {
+        match result {
+            Ok(response) => {
+                response.status().is_server_error()
+                    || response.status() == StatusCode::TOO_MANY_REQUESTS
+            }
+            Err(err) => err.is_timeout() || err.is_connect() || err.is_request(),
+        }
+    }
+
+    /// Returns the time to wait before retrying after the failed attempt `attempt`, counted from 0.
+    ///
+    /// The delay doubles with every attempt, and is shortened by a random amount of up to half of it
+    /// so that clients failing together do not retry together.
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(MAX_DELAY);
+        // Every `RandomState` is seeded randomly, which is enough randomness for a jitter.
+        let random = RandomState::new().build_hasher().finish();
+        let jitter = delay.mul_f64((random as f64 / u64::MAX as f64) / 2.0);
+        delay - jitter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let retry_policy = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_millis(100),
+        };
+        for (attempt, expected) in [(0, 100), (1, 200), (3, 800)] {
+            let delay = retry_policy.delay(attempt);
+            assert!(delay <= Duration::from_millis(expected));
+            assert!(delay >= Duration::from_millis(expected / 2));
+        }
+        assert!(retry_policy.delay(20) <= MAX_DELAY);
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex as SyncMutex, MutexGuard};
+
+use futures::lock::Mutex;
+
+use crate::fpl_error::FplError;
+
+/// Requests in flight, by key, shared by the clones of `Fpl` so that concurrent calls making the
+/// same request, such as for the live data of the same gameweek, make it once and all receive its
+/// result, instead of all making it.
+///
+/// Nothing is kept once a request is done: a call made after it makes the request again.
+pub(crate) struct SingleFlight<K, T> {
+    in_flight: SyncMutex<HashMap<K, Arc<Flight<T>>>>,
+}
+
+/// A request in flight, locked by the call making it until it is done.
+struct Flight<T> {
+    /// The value the request returned, if it succeeded.
+    value: Mutex<Option<T>>,
+}
+
+impl<K: Eq + Hash + Clone, T: Clone> SingleFlight<K, T> {
+    /// Creates an instance with no requests in flight.
+    pub(crate) fn new() -> SingleFlight<K, T> {
+        SingleFlight {
+            in_flight: SyncMutex::new(HashMap::new()),
+        }
+    }
+
+    /// Asynchronously returns the value of the request in flight for `key`, or makes it with `fetch`
+    /// if there is none.
+    ///
+    /// Errors cannot be copied, so if the request fails, the calls which were waiting for it make it
+    /// again one at a time, each returning its own result.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the error of `fetch` if it makes the request and the request fails.
+    pub(crate) async fn run<F, Fut>(&self, key: K, fetch: F) -> Result<T, FplError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, FplError>>,
+    {
+        let flight = Arc::clone(self.in_flight_lock().entry(key.clone()).or_insert_with(|| {
+            Arc::new(Flight {
+                value: Mutex::new(None),
+            })
+        }));
+        let mut value = flight.value.lock().await;
+        if let Some(value) = &*value {
+            return Ok(value.clone());
+        }
+        let result = fetch().await;
+        {
+            let mut in_flight = self.in_flight_lock();
+            // A later call may have started another flight for the key since this one was removed.
+            if in_flight
+                .get(&key)
+                .is_some_and(|in_flight| Arc::ptr_eq(in_flight, &flight))
+            {
+                in_flight.remove(&key);
+            }
+        }
+        if let Ok(fetched) = &result {
+            *value = Some(fetched.clone());
+        }
+        result
+    }
+
+    // A panic while the map is locked can't leave it inconsistent, since flights are only inserted or removed whole.
+    fn in_flight_lock(&self) -> MutexGuard<'_, HashMap<K, Arc<Flight<T>>>> {
+        self.in_flight.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
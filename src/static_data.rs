@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::models::bootstrap_static::{BootstrapStatic, Player, Team};
+
+/// The static data, with indices of its players and teams built once when it is fetched,
+/// so that looking one up does not scan them all.
+#[derive(Debug)]
+pub(crate) struct StaticData {
+    bootstrap_static: BootstrapStatic,
+    /// The position of every player in `elements`, by id.
+    players_by_id: HashMap<i64, usize>,
+    /// The position of every player in `elements`, by code.
+    players_by_code: HashMap<i64, usize>,
+    /// The position of every team in `teams`, by id.
+    teams_by_id: HashMap<i64, usize>,
+}
+
+impl StaticData {
+    /// Indexes the players and teams of `bootstrap_static`.
+    pub(crate) fn new(bootstrap_static: BootstrapStatic) -> StaticData {
+        StaticData {
+            players_by_id: index(&bootstrap_static.elements, |player| player.id),
+            players_by_code: index(&bootstrap_static.elements, |player| player.code),
+            teams_by_id: index(&bootstrap_static.teams, |team| team.id),
+            bootstrap_static,
+        }
+    }
+
+    /// Returns the player with the id `player_id`, if any.
+    pub(crate) fn player(&self, player_id: i64) -> Option<&Player> {
+        let position = *self.players_by_id.get(&player_id)?;
+        self.bootstrap_static.elements.get(position)
+    }
+
+    /// Returns the player with the code `code`, if any.
+    pub(crate) fn player_by_code(&self, code: i64) -> Option<&Player> {
+        let position = *self.players_by_code.get(&code)?;
+        self.bootstrap_static.elements.get(position)
+    }
+
+    /// Returns the team with the id `team_id`, if any.
+    pub(crate) fn team(&self, team_id: i64) -> Option<&Team> {
+        let position = *self.teams_by_id.get(&team_id)?;
+        self.bootstrap_static.teams.get(position)
+    }
+}
+
+impl Deref for StaticData {
+    type Target = BootstrapStatic;
+
+    fn deref(&self) -> &BootstrapStatic {
+        &self.bootstrap_static
+    }
+}
+
+/// Returns the position of every item by its key, keeping the first item of a duplicate key as a scan would.
+fn index<T>(items: &[T], key: impl Fn(&T) -> i64) -> HashMap<i64, usize> {
+    let mut index = HashMap::with_capacity(items.len());
+    for (position, item) in items.iter().enumerate() {
+        index.entry(key(item)).or_insert(position);
+    }
+    index
+}